@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use chrono::{Months, NaiveDate, Utc};
 use reqwest::{RequestBuilder, Url};
 use serde::Deserialize;
+use strum::IntoEnumIterator;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
@@ -11,8 +12,8 @@ use crate::constants::{
 };
 use crate::models::meta::GameStatus;
 use crate::models::{
-    PlatformType, PlayerProfile, PlaytimeProfile, PlaytimeResponse, RankedV2Response,
-    StatisticResponse,
+    GameModeOrAll, PlatformType, PlayerProfile, PlaytimeProfile, PlaytimeResponse,
+    RankedV2Response, StatisticResponse,
 };
 
 pub type Result<T> = core::result::Result<T, ConnectError>;
@@ -30,8 +31,11 @@ pub trait SiegeClient: Sync + Send {
 
     async fn get_maps(&self, player_id: Uuid) -> Result<StatisticResponse>;
 
-    /// Get the current status of Siege's servers.
-    async fn siege_status(&self) -> Result<Vec<GameStatus>>;
+    /// Get the current status of Ubisoft's game servers.
+    ///
+    /// Only instances whose name starts with `title` are returned, e.g.
+    /// `SIEGE_TITLE` for Siege's servers. If `None`, every title is included.
+    async fn siege_status(&self, title: Option<String>) -> Result<Vec<GameStatus>>;
 }
 
 #[derive(Debug)]
@@ -148,7 +152,7 @@ impl SiegeClient for Client {
         })
     }
 
-    async fn siege_status(&self) -> Result<Vec<GameStatus>> {
+    async fn siege_status(&self, title: Option<String>) -> Result<Vec<GameStatus>> {
         reqwest::get(UBI_GAME_STATUS_URL)
             .await
             .map_err(ConnectError::ConnectionError)?
@@ -161,7 +165,11 @@ impl SiegeClient for Client {
             })
             .map(|s| {
                 s.into_iter()
-                    .filter(|x| x.name().starts_with("Rainbow Six Siege"))
+                    .filter(|x| {
+                        title
+                            .as_ref()
+                            .map_or(true, |title| x.name().starts_with(title))
+                    })
                     .collect::<Vec<GameStatus>>()
             })
     }
@@ -252,7 +260,11 @@ fn create_summary_query(player_id: Uuid, aggregation: AggregationType) -> Url {
             ),
             (
                 "gameMode",
-                ["all", "ranked", "cansal", "unranked"].join(",").as_str(),
+                GameModeOrAll::iter()
+                    .map(|mode| mode.to_string().to_lowercase())
+                    .collect::<Vec<_>>()
+                    .join(",")
+                    .as_str(),
             ),
             (
                 "teamRole",
@@ -272,7 +284,7 @@ mod test {
     use lazy_static::lazy_static;
     use tracing_test::traced_test;
 
-    use crate::auth::Auth;
+    use crate::{auth::Auth, models::meta::SIEGE_TITLE};
 
     use super::*;
 
@@ -291,7 +303,7 @@ mod test {
 
     #[test]
     fn operators_url() {
-        let expected = "https://prod.datadev.ubisoft.com/v1/profiles/e7679633-31ff-4f44-8cfd-d0ff81e2c10a/playerstats?view=current&platformGroup=PC&aggregation=operators&spaceId=5172a557-50b5-4665-b7db-e3f2e8c5041d&gameMode=all%2Cranked%2Ccansal%2Cunranked&teamRole=all%2CAttacker%2CDefender";
+        let expected = "https://prod.datadev.ubisoft.com/v1/profiles/e7679633-31ff-4f44-8cfd-d0ff81e2c10a/playerstats?view=current&platformGroup=PC&aggregation=operators&spaceId=5172a557-50b5-4665-b7db-e3f2e8c5041d&gameMode=all%2Cranked%2Ccasual%2Cunranked&teamRole=all%2CAttacker%2CDefender";
 
         let actual = create_summary_query(mock_player_id(), AggregationType::Operators);
         assert_eq!(actual.as_str(), expected);
@@ -392,7 +404,11 @@ mod test {
 
     #[tokio::test]
     async fn retreive_server_status() {
-        let status = get_client().await.siege_status().await.unwrap();
+        let status = get_client()
+            .await
+            .siege_status(Some(SIEGE_TITLE.to_string()))
+            .await
+            .unwrap();
         assert_eq!(status.len(), 7);
         status
             .iter()
@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+
+use derive_getters::Getters;
+use lazy_static::lazy_static;
+use serde::{de::Error, Deserialize};
+use strum::{Display, EnumIter, EnumString};
+
+use crate::operator::Operator;
+
+/// Every weapon available to an operator along with its attachments.
+const WEAPONS_JSON: &str = include_str!("weapons.json");
+/// The weapons, gadgets and unique ability of each operator, keyed by their safename.
+const LOADOUTS_JSON: &str = include_str!("loadouts.json");
+
+lazy_static! {
+    static ref ARMORY: Armory =
+        Armory::from_json(WEAPONS_JSON, LOADOUTS_JSON).expect("armory should be valid");
+    static ref EMPTY_LOADOUT: Loadout = Loadout {
+        name: Operator::Unknown,
+        primary_weapons: vec![],
+        secondary_weapons: vec![],
+        gadgets: vec![],
+        unique_ability: None,
+    };
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash, Display, EnumString, EnumIter)]
+pub enum WeaponType {
+    #[strum(serialize = "Assault rifle")]
+    AssaultRifle,
+    #[strum(serialize = "Submachine gun")]
+    SubmachineGun,
+    #[strum(serialize = "Light machine gun")]
+    LightMachineGun,
+    #[strum(serialize = "Marksman rifle")]
+    MarksmanRifle,
+    Shotgun,
+    Handgun,
+    #[strum(serialize = "Machine pistol")]
+    MachinePistol,
+    Shield,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash, Display, EnumString, EnumIter)]
+pub enum Sight {
+    #[strum(serialize = "Red dot")]
+    RedDot,
+    Holographic,
+    Reflex,
+    #[strum(serialize = "Magnified 1.5x")]
+    Magnified1_5,
+    #[strum(serialize = "Magnified 2x")]
+    Magnified2,
+    #[strum(serialize = "Magnified 2.5x")]
+    Magnified2_5,
+    #[strum(serialize = "Magnified 3x")]
+    Magnified3,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash, Display, EnumString, EnumIter)]
+pub enum Barrel {
+    #[strum(serialize = "Flash hider")]
+    FlashHider,
+    Compensator,
+    #[strum(serialize = "Muzzle brake")]
+    MuzzleBrake,
+    Suppressor,
+    #[strum(serialize = "Extended barrel")]
+    ExtendedBarrel,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash, Display, EnumString, EnumIter)]
+pub enum Grip {
+    #[strum(serialize = "Vertical grip")]
+    VerticalGrip,
+    #[strum(serialize = "Angled grip")]
+    AngledGrip,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash, Display, EnumString, EnumIter)]
+pub enum UnderBarrel {
+    Laser,
+}
+
+/// Secondary gadgets shared between operators.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash, Display, EnumString, EnumIter)]
+#[strum(ascii_case_insensitive)]
+pub enum Gadget {
+    #[strum(serialize = "Frag grenade")]
+    FragGrenade,
+    #[strum(serialize = "Smoke grenade")]
+    SmokeGrenade,
+    #[strum(serialize = "Stun grenade")]
+    StunGrenade,
+    Claymore,
+    #[strum(serialize = "Breach charge")]
+    BreachCharge,
+    #[strum(serialize = "Hard breach charge")]
+    HardBreachCharge,
+    #[strum(serialize = "Impact EMP grenade")]
+    ImpactEmpGrenade,
+    #[strum(serialize = "Barbed wire")]
+    BarbedWire,
+    #[strum(serialize = "Deployable shield")]
+    DeployableShield,
+    #[strum(serialize = "Nitro cell")]
+    NitroCell,
+    #[strum(serialize = "Bulletproof camera")]
+    BulletproofCamera,
+    #[strum(serialize = "Impact grenade")]
+    ImpactGrenade,
+    #[strum(serialize = "Proximity alarm")]
+    ProximityAlarm,
+    #[strum(serialize = "Observation blocker")]
+    ObservationBlocker,
+}
+
+/// A weapon and the attachments which can be equipped on it. Empty lists
+/// mean that the weapon has no options in that slot, e.g. handguns only use
+/// iron sights.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Getters)]
+pub struct Weapon {
+    name: String,
+    kind: WeaponType,
+    sights: Vec<Sight>,
+    barrels: Vec<Barrel>,
+    grips: Vec<Grip>,
+    under_barrels: Vec<UnderBarrel>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Getters)]
+pub struct UniqueAbility {
+    name: String,
+    description: String,
+}
+
+/// The gameplay kit of an operator.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct Loadout {
+    name: Operator,
+    primary_weapons: Vec<Weapon>,
+    secondary_weapons: Vec<Weapon>,
+    gadgets: Vec<Gadget>,
+    unique_ability: Option<UniqueAbility>,
+}
+
+impl Loadout {
+    /// Iterate over both primary and secondary weapons.
+    pub fn weapons(&self) -> impl Iterator<Item = &Weapon> {
+        self.primary_weapons
+            .iter()
+            .chain(self.secondary_weapons.iter())
+    }
+
+    /// Check if any primary or secondary weapon is of the given type.
+    pub fn has_weapon_type(&self, kind: WeaponType) -> bool {
+        self.weapons().any(|weapon| weapon.kind == kind)
+    }
+
+    /// Check if the gadget is one of the secondary gadgets of the operator.
+    pub fn has_gadget(&self, gadget: Gadget) -> bool {
+        self.gadgets.contains(&gadget)
+    }
+}
+
+/// Loadouts as stored in the data file, which reference weapons by name.
+#[derive(Deserialize)]
+struct LoadoutEntry {
+    name: Operator,
+    primary_weapons: Vec<String>,
+    secondary_weapons: Vec<String>,
+    gadgets: Vec<Gadget>,
+    unique_ability: Option<UniqueAbility>,
+}
+
+/// Registry of all weapons and the loadout of every known operator.
+#[derive(Debug)]
+pub struct Armory {
+    weapons: HashMap<String, Weapon>,
+    loadouts: HashMap<Operator, Loadout>,
+}
+
+impl Armory {
+    /// Parse the weapons and loadouts from JSON. Fails if a loadout references
+    /// a weapon which does not exist.
+    pub fn from_json(weapons: &str, loadouts: &str) -> Result<Self, serde_json::Error> {
+        let weapons: HashMap<String, Weapon> = serde_json::from_str(weapons)?;
+        let entries: HashMap<String, LoadoutEntry> = serde_json::from_str(loadouts)?;
+
+        let resolve = |names: Vec<String>| {
+            names
+                .into_iter()
+                .map(|name| {
+                    weapons.get(&name).cloned().ok_or_else(|| {
+                        serde_json::Error::custom(format!("unknown weapon '{name}'"))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        let loadouts = entries
+            .into_values()
+            .map(|entry| {
+                Ok((
+                    entry.name,
+                    Loadout {
+                        name: entry.name,
+                        primary_weapons: resolve(entry.primary_weapons)?,
+                        secondary_weapons: resolve(entry.secondary_weapons)?,
+                        gadgets: entry.gadgets,
+                        unique_ability: entry.unique_ability,
+                    },
+                ))
+            })
+            .collect::<Result<HashMap<_, _>, serde_json::Error>>()?;
+
+        Ok(Self { weapons, loadouts })
+    }
+
+    /// Get a weapon by its name.
+    pub fn weapon(&self, name: &str) -> Option<&Weapon> {
+        self.weapons.get(name)
+    }
+
+    /// Iterate over all weapons.
+    pub fn weapons(&self) -> impl Iterator<Item = &Weapon> {
+        self.weapons.values()
+    }
+
+    /// Get the loadout of an operator, if it is part of the armory.
+    pub fn loadout(&self, operator: Operator) -> Option<&Loadout> {
+        self.loadouts.get(&operator)
+    }
+
+    /// Iterate over the loadouts of all operators.
+    pub fn loadouts(&self) -> impl Iterator<Item = &Loadout> {
+        self.loadouts.values()
+    }
+}
+
+/// Get the armory with all weapons and loadouts.
+pub fn armory() -> &'static Armory {
+    &ARMORY
+}
+
+/// Get the loadout of an operator. Operators which are not part of the
+/// armory, i.e. `Operator::Unknown`, get an empty loadout.
+pub fn get_loadout(operator: Operator) -> &'static Loadout {
+    armory().loadout(operator).unwrap_or(&EMPTY_LOADOUT)
+}
+
+#[cfg(test)]
+mod test {
+    use strum::IntoEnumIterator;
+
+    use crate::{data::operator::get_operator_details, game_models::Side};
+
+    use super::*;
+
+    #[test]
+    fn armory_contains_every_operator() {
+        Operator::iter()
+            .filter(|op| *op != Operator::Unknown)
+            .for_each(|op| {
+                assert_eq!(*armory().loadout(op).unwrap().name(), op, "{op}");
+            });
+
+        assert_eq!(*get_loadout(Operator::Unknown).name(), Operator::Unknown);
+        assert!(get_loadout(Operator::Unknown).weapons().next().is_none());
+    }
+
+    #[test]
+    fn every_weapon_is_used() {
+        armory().weapons().for_each(|weapon| {
+            assert!(
+                armory()
+                    .loadouts()
+                    .any(|loadout| loadout.weapons().any(|x| x == weapon)),
+                "{} is not used by any operator",
+                weapon.name()
+            );
+        });
+    }
+
+    #[test]
+    fn gadgets_match_side() {
+        let attacker_gadgets = [
+            Gadget::FragGrenade,
+            Gadget::SmokeGrenade,
+            Gadget::StunGrenade,
+            Gadget::Claymore,
+            Gadget::BreachCharge,
+            Gadget::HardBreachCharge,
+            Gadget::ImpactEmpGrenade,
+        ];
+
+        armory().loadouts().for_each(|loadout| {
+            let side = *get_operator_details(*loadout.name()).side();
+            loadout.gadgets().iter().for_each(|gadget| {
+                assert_eq!(
+                    attacker_gadgets.contains(gadget),
+                    side == Side::Attacker,
+                    "{} cannot have {gadget}",
+                    loadout.name()
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn defenders_with_shotgun_and_impact_grenades() {
+        let mut operators = armory()
+            .loadouts()
+            .filter(|x| *get_operator_details(*x.name()).side() == Side::Defender)
+            .filter(|x| x.has_weapon_type(WeaponType::Shotgun))
+            .filter(|x| x.has_gadget(Gadget::ImpactGrenade))
+            .map(|x| *x.name())
+            .collect::<Vec<_>>();
+        operators.sort_by_key(|x| x.to_string());
+
+        assert!(operators.contains(&Operator::Valkyrie));
+        assert!(operators.contains(&Operator::Rook));
+        assert!(!operators.contains(&Operator::Mute));
+    }
+
+    #[test]
+    fn validate_getters() {
+        let loadout = get_loadout(Operator::Buck);
+
+        assert_eq!(*loadout.name(), Operator::Buck);
+        assert_eq!(
+            loadout
+                .primary_weapons()
+                .iter()
+                .map(|x| x.name().as_str())
+                .collect::<Vec<_>>(),
+            vec!["C8-SFW", "CAMRS"]
+        );
+        assert_eq!(loadout.secondary_weapons().len(), 1);
+        assert_eq!(
+            *loadout.gadgets(),
+            vec![Gadget::StunGrenade, Gadget::HardBreachCharge]
+        );
+        assert_eq!(
+            loadout.unique_ability().as_ref().unwrap().name(),
+            "Skeleton Key"
+        );
+        assert!(loadout.has_weapon_type(WeaponType::MarksmanRifle));
+        assert!(!loadout.has_weapon_type(WeaponType::Shotgun));
+
+        let weapon = armory().weapon("CAMRS").unwrap();
+        assert_eq!(*weapon.kind(), WeaponType::MarksmanRifle);
+        assert!(weapon.sights().contains(&Sight::Magnified3));
+        assert!(weapon.barrels().contains(&Barrel::Suppressor));
+        assert!(weapon.grips().contains(&Grip::AngledGrip));
+        assert_eq!(*weapon.under_barrels(), vec![UnderBarrel::Laser]);
+    }
+
+    #[test]
+    fn recruit_has_no_unique_ability() {
+        assert!(get_loadout(Operator::Recruit).unique_ability().is_none());
+    }
+
+    #[test]
+    fn armory_with_unknown_weapon() {
+        let loadouts = r#"{ "ram": { "name": "Ram", "primary_weapons": ["R4-C"], "secondary_weapons": [], "gadgets": [] } }"#;
+
+        let err = Armory::from_json("{}", loadouts).unwrap_err();
+        assert!(err.to_string().contains("unknown weapon 'R4-C'"));
+    }
+
+    #[test]
+    fn parse_gadget() {
+        assert_eq!(
+            "impact grenade".parse::<Gadget>().unwrap(),
+            Gadget::ImpactGrenade
+        );
+        assert_eq!(Gadget::HardBreachCharge.to_string(), "Hard breach charge");
+    }
+}
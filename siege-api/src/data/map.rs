@@ -0,0 +1,219 @@
+use std::collections::{BTreeMap, HashMap};
+
+use derive_getters::Getters;
+use lazy_static::lazy_static;
+use serde::Deserialize;
+use strum::{Display, EnumIter, EnumString};
+
+use crate::{game_models::Season, maps::Map};
+
+/// Details for all maps, keyed by the name of the map in lowercase.
+const MAPS_JSON: &str = include_str!("maps.json");
+
+lazy_static! {
+    static ref CATALOGUE: MapCatalogue =
+        MapCatalogue::from_json(MAPS_JSON).expect("map catalogue should be valid");
+    static ref UNKNOWN_MAP: MapDetails = MapDetails {
+        name: Map::Unknown,
+        release_season: Season::Unknown,
+        playlists: vec![],
+        sites: vec![],
+    };
+}
+
+/// Playlists a map can be part of.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash, Display, EnumString, EnumIter)]
+pub enum Playlist {
+    Ranked,
+    Unranked,
+    #[strum(serialize = "Quick match")]
+    QuickMatch,
+    #[strum(serialize = "Team deathmatch")]
+    TeamDeathmatch,
+}
+
+#[derive(
+    Debug, Clone, Copy, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Display, EnumIter,
+)]
+pub enum Floor {
+    #[strum(serialize = "B")]
+    Basement,
+    #[strum(serialize = "1F")]
+    FirstFloor,
+    #[strum(serialize = "2F")]
+    SecondFloor,
+    #[strum(serialize = "3F")]
+    ThirdFloor,
+    #[strum(serialize = "4F")]
+    FourthFloor,
+}
+
+/// A bomb site, named after the two rooms it consists of.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Getters)]
+pub struct BombSite {
+    floor: Floor,
+    name: String,
+}
+
+#[derive(Debug, Deserialize, Getters)]
+pub struct MapDetails {
+    name: Map,
+    release_season: Season,
+    playlists: Vec<Playlist>,
+    sites: Vec<BombSite>,
+}
+
+impl MapDetails {
+    /// Check if the map is currently part of the playlist.
+    pub fn in_playlist(&self, playlist: Playlist) -> bool {
+        self.playlists.contains(&playlist)
+    }
+
+    /// Get the bomb sites grouped by floor, from the basement and up.
+    pub fn sites_by_floor(&self) -> BTreeMap<Floor, Vec<&BombSite>> {
+        self.sites.iter().fold(BTreeMap::new(), |mut acc, site| {
+            acc.entry(site.floor).or_insert_with(Vec::new).push(site);
+            acc
+        })
+    }
+}
+
+/// Registry of the details for every known map.
+#[derive(Debug)]
+pub struct MapCatalogue {
+    maps: HashMap<Map, MapDetails>,
+}
+
+impl MapCatalogue {
+    /// Parse a catalogue from JSON.
+    pub fn from_json(content: &str) -> Result<Self, serde_json::Error> {
+        let entries: HashMap<String, MapDetails> = serde_json::from_str(content)?;
+
+        Ok(Self {
+            maps: entries
+                .into_values()
+                .map(|details| (details.name, details))
+                .collect(),
+        })
+    }
+
+    /// Get the details for a map, if it is part of the catalogue.
+    pub fn get(&self, map: Map) -> Option<&MapDetails> {
+        self.maps.get(&map)
+    }
+
+    /// Iterate over the details for all maps in the catalogue.
+    pub fn iter(&self) -> impl Iterator<Item = &MapDetails> {
+        self.maps.values()
+    }
+
+    /// Iterate over the maps which are part of the playlist.
+    pub fn playlist(&self, playlist: Playlist) -> impl Iterator<Item = &MapDetails> {
+        self.iter().filter(move |x| x.in_playlist(playlist))
+    }
+
+    pub fn len(&self) -> usize {
+        self.maps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.maps.is_empty()
+    }
+}
+
+/// Get the catalogue with details for all maps.
+pub fn map_catalogue() -> &'static MapCatalogue {
+    &CATALOGUE
+}
+
+/// Get the details for a map. Maps which are not part of the catalogue, i.e.
+/// `Map::Unknown`, get placeholder details.
+pub fn get_map_details(map: Map) -> &'static MapDetails {
+    map_catalogue().get(map).unwrap_or(&UNKNOWN_MAP)
+}
+
+#[cfg(test)]
+mod test {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn catalogue_contains_every_map() {
+        Map::iter().filter(|x| *x != Map::Unknown).for_each(|map| {
+            assert_eq!(*get_map_details(map).name(), map);
+        });
+
+        assert_eq!(map_catalogue().len(), Map::iter().count() - 1);
+        assert_eq!(*get_map_details(Map::Unknown).name(), Map::Unknown);
+    }
+
+    #[test]
+    fn catalogue_has_no_unknown_values() {
+        map_catalogue().iter().for_each(|details| {
+            assert_ne!(
+                *details.release_season(),
+                Season::Unknown,
+                "{}",
+                details.name()
+            );
+        });
+    }
+
+    #[test]
+    fn ranked_maps_have_bomb_sites() {
+        map_catalogue()
+            .playlist(Playlist::Ranked)
+            .for_each(|details| {
+                assert!(details.sites().len() >= 4, "{}", details.name());
+            });
+    }
+
+    #[test]
+    fn ranked_pool() {
+        let ranked = map_catalogue()
+            .playlist(Playlist::Ranked)
+            .map(|x| *x.name())
+            .collect::<Vec<_>>();
+
+        assert!(ranked.contains(&Map::ClubHouse));
+        assert!(ranked.contains(&Map::ConsulateV2));
+        assert!(!ranked.contains(&Map::Favela));
+        assert!(!ranked.contains(&Map::Consulate));
+        assert!(!ranked.contains(&Map::CloseQuarter));
+    }
+
+    #[test]
+    fn validate_getters() {
+        let details = get_map_details(Map::Yacht);
+
+        assert_eq!(*details.name(), Map::Yacht);
+        assert_eq!(*details.release_season(), Season::Y1S1);
+        assert_eq!(*details.playlists(), vec![Playlist::QuickMatch]);
+        assert!(details.in_playlist(Playlist::QuickMatch));
+        assert!(!details.in_playlist(Playlist::Ranked));
+        assert_eq!(details.sites().len(), 4);
+    }
+
+    #[test]
+    fn sites_are_grouped_by_floor() {
+        let sites = get_map_details(Map::Yacht).sites_by_floor();
+
+        assert_eq!(
+            sites.keys().copied().collect::<Vec<_>>(),
+            vec![
+                Floor::Basement,
+                Floor::FirstFloor,
+                Floor::SecondFloor,
+                Floor::FourthFloor
+            ]
+        );
+        assert_eq!(sites[&Floor::FourthFloor][0].name(), "Maps Room / Cockpit");
+        assert_eq!(Floor::FourthFloor.to_string(), "4F");
+    }
+
+    #[test]
+    fn catalogue_from_invalid_json() {
+        assert!(MapCatalogue::from_json("{ \"yacht\": { \"name\": \"YACHT\" } }").is_err());
+    }
+}
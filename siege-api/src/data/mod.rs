@@ -1,2 +1,4 @@
+pub mod loadout;
+pub mod map;
 pub mod operator;
 pub mod rank;
@@ -1,4 +1,5 @@
-use strum::{Display, EnumIter, FromRepr};
+use serde::Deserialize;
+use strum::{Display, EnumIter, EnumString, FromRepr};
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, FromRepr, Display, EnumIter, PartialEq, Eq, PartialOrd, Ord)]
@@ -50,7 +51,50 @@ pub enum Rank {
     Champions,
 }
 
+/// The group of ranks sharing a name, e.g. `GoldIII` and `GoldI` are both
+/// `Gold`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Display,
+    EnumIter,
+    EnumString,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Deserialize,
+)]
+pub enum RankTier {
+    Unranked,
+    Copper,
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+    Emerald,
+    Diamond,
+    Champions,
+}
+
 impl Rank {
+    pub fn tier(&self) -> RankTier {
+        use Rank::*;
+        match self {
+            Unranked => RankTier::Unranked,
+            CopperV | CopperIV | CopperIII | CopperII | CopperI => RankTier::Copper,
+            BronzeV | BronzeIV | BronzeIII | BronzeII | BronzeI => RankTier::Bronze,
+            SilverV | SilverIV | SilverIII | SilverII | SilverI => RankTier::Silver,
+            GoldV | GoldIV | GoldIII | GoldII | GoldI => RankTier::Gold,
+            PlatinumV | PlatinumIV | PlatinumIII | PlatinumII | PlatinumI => RankTier::Platinum,
+            EmeraldV | EmeraldIV | EmeraldIII | EmeraldII | EmeraldI => RankTier::Emerald,
+            DiamondV | DiamondIV | DiamondIII | DiamondII | DiamondI => RankTier::Diamond,
+            Champions => RankTier::Champions,
+        }
+    }
+
     pub fn from_mmr(mmr: u64) -> Rank {
         use Rank::*;
         match mmr {
@@ -110,6 +154,23 @@ mod test {
         }
     }
 
+    #[test]
+    fn tier() {
+        assert_eq!(Rank::Unranked.tier(), RankTier::Unranked);
+        assert_eq!(Rank::GoldV.tier(), RankTier::Gold);
+        assert_eq!(Rank::GoldI.tier(), RankTier::Gold);
+        assert_eq!(Rank::Champions.tier(), RankTier::Champions);
+
+        // Every tier except unranked and champions has five ranks.
+        for tier in RankTier::iter() {
+            let ranks = Rank::iter().filter(|x| x.tier() == tier).count();
+            match tier {
+                RankTier::Unranked | RankTier::Champions => assert_eq!(ranks, 1),
+                _ => assert_eq!(ranks, 5),
+            }
+        }
+    }
+
     #[test]
     fn from_mmr() {
         for (rank, mmr) in zip(Rank::iter(), (900..=4500).step_by(100)) {
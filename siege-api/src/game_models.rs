@@ -1,8 +1,11 @@
 use serde::Deserialize;
-use strum::{Display, EnumString, FromRepr};
+use strum::{Display, EnumIter, EnumString, FromRepr};
 
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, EnumString, Display, FromRepr)]
+#[derive(
+    Debug, Clone, Copy, Deserialize, PartialEq, Eq, PartialOrd, Ord, EnumString, Display, FromRepr,
+)]
+#[strum(ascii_case_insensitive)]
 pub enum Season {
     Y0S0,
     Y1S1,
@@ -40,28 +43,61 @@ pub enum Season {
     Unknown,
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, FromRepr, Display)]
+#[serde(try_from = "u8")]
 pub enum Health {
     Low = 1,
     Medium = 2,
     High = 3,
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+impl TryFrom<u8> for Health {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_repr(value).ok_or(format!("'{value}' is not a valid health"))
+    }
+}
+
+#[repr(u8)]
+#[derive(
+    Debug, Clone, Copy, Deserialize, PartialEq, Eq, FromRepr, Display, EnumString, EnumIter,
+)]
+#[serde(try_from = "u8")]
 pub enum Speed {
     Slow = 1,
     Normal = 2,
     Fast = 3,
 }
 
+impl TryFrom<u8> for Speed {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_repr(value).ok_or(format!("'{value}' is not a valid speed"))
+    }
+}
+
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, strum::Display, strum::EnumString)]
-#[serde(rename_all = "lowercase")]
+#[serde(from = "String")]
+#[strum(ascii_case_insensitive)]
 pub enum Side {
     Attacker,
     Defender,
+    /// A side which is not supported yet. The raw value is logged when
+    /// deserialized.
+    Unknown,
+}
+
+impl From<String> for Side {
+    fn from(value: String) -> Self {
+        crate::models::mappers::parse_known(&value).unwrap_or(Self::Unknown)
+    }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Display, EnumString, EnumIter)]
+#[strum(ascii_case_insensitive)]
 pub enum Role {
     Anchor,
     AntiHardBreach,
@@ -97,4 +133,44 @@ mod test {
     fn number_to_season() {
         assert_eq!(Season::from_repr(29).unwrap(), Season::Y8S1);
     }
+
+    #[test]
+    fn order_seasons() {
+        assert!(Season::Y1S1 < Season::Y8S3);
+        assert!("y7s2".parse::<Season>().unwrap() > Season::Y7S1);
+    }
+
+    #[test]
+    fn display_unknown_role() {
+        let role: Role = serde_json::from_str("\"Bodyguard\"").unwrap();
+        assert_eq!(role, Role::Unknown);
+        assert_eq!(role.to_string(), "Unknown");
+    }
+
+    #[test]
+    fn deserialize_health_and_speed() {
+        assert_eq!(serde_json::from_str::<Health>("1").unwrap(), Health::Low);
+        assert_eq!(serde_json::from_str::<Health>("3").unwrap(), Health::High);
+        assert!(serde_json::from_str::<Health>("4").is_err());
+
+        assert_eq!(serde_json::from_str::<Speed>("2").unwrap(), Speed::Normal);
+        assert!(serde_json::from_str::<Speed>("0").is_err());
+    }
+
+    #[test]
+    fn deserialize_side() {
+        assert_eq!(
+            serde_json::from_str::<Side>("\"attacker\"").unwrap(),
+            Side::Attacker
+        );
+        assert_eq!(
+            serde_json::from_str::<Side>("\"defender\"").unwrap(),
+            Side::Defender
+        );
+        assert_eq!(
+            serde_json::from_str::<Side>("\"spectator\"").unwrap(),
+            Side::Unknown
+        );
+        assert_eq!(Side::Unknown.to_string(), "Unknown");
+    }
 }
@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, EnumString};
 
+pub use crate::data::map::get_map_details;
+
 #[derive(
-    Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, EnumString, EnumIter, Display,
+    Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, EnumString, EnumIter, Display,
 )]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Map {
@@ -54,8 +56,10 @@ pub enum Map {
 }
 
 impl Map {
-    pub fn image(&self) -> &str {
-        match self {
+    /// Get a URL for a screenshot of the map. Maps without a verified image
+    /// return `None`, rather than borrowing the image of another map.
+    pub fn image(&self) -> Option<&'static str> {
+        let url = match self {
             Self::NighthavenLabs => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/57i2PyuzpgVFzOvLUSAItO/636e57b198377a5a5d1d35492b52b808/Nighthaven_labs_screen.jpg",
             Self::StadiumBravo => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/4sQkH6y0h79oYEHuWHGAv/0103ee95bd83c8e222b32f7784e323da/r6s_maps_stadium.jpg",
             Self::CloseQuarter => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/6u3cuepKWT8IFdaGznfc3k/d470334bddf5d6313c15879cde524615/r6s_maps_closequarters.jpg",
@@ -63,10 +67,9 @@ impl Map {
             Self::Bank => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/6ilgtuzucX7hEu2MvjhRtp/0bb6e106d78625ea218a572fbb7a5157/r6-maps-bank.jpg",
             Self::Border => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/4hqsrL3cokFqedkfjiEaGf/c73f6714b535263a18e4de2ca2405dd1/r6-maps-border__1_.jpg",
             Self::Chalet => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/Km3ZJUM7ZMVbGsi6gad5Y/c48162371342d9f15386c77a3766315b/r6-maps-chalet.jpg",
-            Self::ClubHouse => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/Km3ZJUM7ZMVbGsi6gad5Y/c48162371342d9f15386c77a3766315b/r6-maps-chalet.jpg",
             Self::Coastline => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/5GfAQ3pXCJnDqiqaDH3Zic/db1722cd699bb864ee8f7b0db951b0c3/r6-maps-coastline.jpg",
+            // The original Consulate was reworked into the current one.
             Self::Consulate | Self::ConsulateV2 => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/6PR2sBla9E6TNurVUfJ0mc/860cab16eb1d4cd27ea356a1c3fe9591/r6-maps-consulate.jpg",
-            Self::Favela => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/6PR2sBla9E6TNurVUfJ0mc/860cab16eb1d4cd27ea356a1c3fe9591/r6-maps-consulate.jpg",
             Self::Fortress => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/1MrLwvq61aSSvvUj3dDiZg/18e267c79b8015a1af509a2e5694b18b/r6-maps-fortress.jpg",
             Self::HerefordBase => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/1QHhMYSliWgWXFLxZj19hz/44197c1d98498d8a77618076a19ce538/r6-maps-hereford.jpg",
             Self::House => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/28OaEZAY3stNFr0wSvW9MB/c7acc97d43486349763acab3c1564414/r6-maps-house.jpg",
@@ -79,8 +82,11 @@ impl Map {
             Self::ThemePark => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/2immPCOZj6tTHMM9zeBg5B/cf09c9c75bc2e70dd38ebf0a12bdb9a2/r6-maps-themepark.jpg",
             Self::Tower => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/6ZMBunxANmzTNr42wwzggb/3a19c506f9e3f910e34da21095686fa9/r6-maps-tower.jpg",
             Self::Villa => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/Io6dxNeHbCbJoF9WLJf9s/ebf89b009affba37df84dcf1934c74e0/r6-maps-villa.jpg",
-            Self::Yacht | Self::Unknown => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/smDP6lSSaB6Daa7bLZxHZ/d6cc60d76e553e91503a474ff0bc148b/r6-maps-yacht.jpg",
-        }
+            Self::Yacht => "https://staticctf.ubisoft.com/J3yJr34U2pZ2Ieem48Dwy9uqj5PNUQTn/smDP6lSSaB6Daa7bLZxHZ/d6cc60d76e553e91503a474ff0bc148b/r6-maps-yacht.jpg",
+            Self::ClubHouse | Self::Favela | Self::Unknown => return None,
+        };
+
+        Some(url)
     }
 }
 
@@ -108,8 +114,32 @@ mod test {
 
     #[test]
     fn image_is_valid() {
-        Map::iter().for_each(|x| {
-            assert!(Url::parse(x.image()).is_ok());
+        Map::iter().filter_map(|x| x.image()).for_each(|x| {
+            assert!(Url::parse(x).is_ok());
         });
     }
+
+    #[test]
+    fn images_are_unique() {
+        let mut images = Map::iter()
+            .filter(|x| *x != Map::Consulate)
+            .filter_map(|x| x.image())
+            .collect::<Vec<_>>();
+        let count = images.len();
+        images.sort();
+        images.dedup();
+
+        assert_eq!(images.len(), count);
+    }
+
+    #[test]
+    fn consulate_shares_image_with_rework() {
+        assert_eq!(Map::Consulate.image(), Map::ConsulateV2.image());
+        assert!(Map::Consulate.image().is_some());
+    }
+
+    #[test]
+    fn unknown_map_has_no_image() {
+        assert_eq!(Map::Unknown.image(), None);
+    }
 }
@@ -12,9 +12,16 @@ pub use playerstats::*;
 use crate::game_models::Season;
 
 pub mod meta;
+pub mod metrics;
 /// This section contains all models related to the `playerstats` endpoint
 mod playerstats;
 
+/// Divide two counts. The ratio is undefined, i.e. `None`, when the
+/// denominator is zero, instead of being NaN or infinite.
+pub fn ratio(numerator: u64, denominator: u64) -> Option<f64> {
+    (denominator != 0).then(|| numerator as f64 / denominator as f64)
+}
+
 #[derive(Debug, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
 pub struct PlayerProfile {
@@ -134,7 +141,7 @@ impl RankedV2Response {
     pub fn get_board(&self, platform: PlatformFamily, play_type: GameMode) -> Option<&FullProfile> {
         self.get_for_platform(platform)
             .and_then(|x| x.get_by_playtype(play_type))
-            .and_then(|x| x.full_profiles.get(0))
+            .and_then(|x| x.full_profiles.first())
     }
 }
 
@@ -175,6 +182,9 @@ pub struct Profile {
     max_rank: u8,
     max_rank_points: u64,
     platform_family: PlatformFamily,
+    /// Current rank, see `data::rank::Rank`.
+    rank: u8,
+    rank_points: u64,
     #[serde(rename = "season_id", deserialize_with = "mappers::u8_to_season")]
     season: Season,
     top_rank_position: i64,
@@ -188,8 +198,9 @@ pub struct SeasonStatistics {
 }
 
 impl SeasonStatistics {
-    pub fn kd(&self) -> f64 {
-        self.kills as f64 / self.deaths as f64
+    /// Calculate the kill/death ratio. Undefined without any deaths.
+    pub fn kd(&self) -> Option<f64> {
+        ratio(self.kills, self.deaths)
     }
 }
 
@@ -215,37 +226,70 @@ impl MatchOutcomes {
     /// Calculate the win rate for the current match outcomes.
     /// Note that this calculation does **not** take abandoned matches into account.
     /// For that see `win_rate_with_abandons`.
-    pub fn win_rate(&self) -> f64 {
-        self.wins as f64 / self.total_matches() as f64
+    pub fn win_rate(&self) -> Option<f64> {
+        ratio(self.wins, self.total_matches())
     }
 
-    pub fn win_rate_with_abandons(&self) -> f64 {
-        self.wins as f64 / self.total_matches_with_abandons() as f64
+    pub fn win_rate_with_abandons(&self) -> Option<f64> {
+        ratio(self.wins, self.total_matches_with_abandons())
     }
 }
 
 #[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, EnumIter)]
-#[serde(rename_all = "lowercase")]
+#[serde(from = "String")]
+#[strum(ascii_case_insensitive)]
 pub enum PlatformFamily {
     Pc,
     Console,
+    /// A platform family which is not supported yet. The raw value is logged
+    /// when deserialized.
+    Unknown,
+}
+
+impl From<String> for PlatformFamily {
+    fn from(value: String) -> Self {
+        mappers::parse_known(&value).unwrap_or(Self::Unknown)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, EnumString, Display, EnumIter)]
-#[serde(rename_all = "lowercase")]
+#[serde(from = "String")]
+#[strum(ascii_case_insensitive)]
 pub enum GameMode {
     Casual,
     Ranked,
     Event,
     Warmup,
     Standard,
+    /// A game mode which is not supported yet. The raw value is logged when
+    /// deserialized.
+    Unknown,
 }
 
-mod mappers {
+impl From<String> for GameMode {
+    fn from(value: String) -> Self {
+        mappers::parse_known(&value).unwrap_or(Self::Unknown)
+    }
+}
+
+pub(crate) mod mappers {
+    use std::str::FromStr;
+
     use serde::Deserializer;
 
     use super::*;
 
+    /// Parse a value returned by Ubisoft into a known enum variant. Values
+    /// which are not recognized are logged, so support for them can be added.
+    pub fn parse_known<T: FromStr>(value: &str) -> Option<T> {
+        let parsed = value.parse().ok();
+        if parsed.is_none() {
+            tracing::warn!("Unknown value '{value}' for {}", std::any::type_name::<T>());
+        }
+
+        parsed
+    }
+
     pub fn int_string_to_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
     where
         D: Deserializer<'de>,
@@ -350,6 +394,7 @@ mod test {
     use std::fs::read_to_string;
 
     use strum::IntoEnumIterator;
+    use tracing_test::traced_test;
 
     use super::*;
 
@@ -360,7 +405,7 @@ mod test {
 
         assert_eq!(
             response.get_for_platform(PlatformFamily::Pc),
-            response.platform_families_full_profiles.get(0),
+            response.platform_families_full_profiles.first(),
         );
 
         // No console data is included in the sample, so `None` is expected.
@@ -372,10 +417,10 @@ mod test {
         let content = read_to_string("../samples/full_profile.json").unwrap();
         let response: RankedV2Response = serde_json::from_str(content.as_str()).unwrap();
 
-        let platforms = response.platform_families_full_profiles.get(0).unwrap();
+        let platforms = response.platform_families_full_profiles.first().unwrap();
 
         assert_eq!(
-            platforms.board_ids_full_profiles.get(0),
+            platforms.board_ids_full_profiles.first(),
             platforms.get_by_playtype(GameMode::Casual)
         );
         assert_eq!(
@@ -399,14 +444,18 @@ mod test {
 
         let expected = response
             .platform_families_full_profiles
-            .get(0)
+            .first()
             .and_then(|x| x.board_ids_full_profiles.get(3))
-            .and_then(|x| x.full_profiles.get(0));
+            .and_then(|x| x.full_profiles.first());
 
         assert_eq!(
             response.get_board(PlatformFamily::Pc, GameMode::Ranked),
             expected
         );
+
+        let profile = expected.unwrap().profile();
+        assert_eq!(*profile.rank(), 4);
+        assert_eq!(*profile.rank_points(), 1300);
     }
 
     #[test]
@@ -432,7 +481,68 @@ mod test {
             match_outcomes: MatchOutcomes::default(),
         };
 
-        assert_eq!(stats.kd(), 1.24);
+        assert_eq!(stats.kd(), Some(1.24));
+    }
+
+    #[test]
+    fn kd_on_season_statistics_without_deaths() {
+        let stats = SeasonStatistics {
+            kills: 3,
+            deaths: 0,
+            match_outcomes: MatchOutcomes::default(),
+        };
+
+        assert_eq!(stats.kd(), None);
+    }
+
+    #[test]
+    fn ratio_is_undefined_without_denominator() {
+        assert_eq!(ratio(1, 4), Some(0.25));
+        assert_eq!(ratio(0, 4), Some(0.0));
+        assert_eq!(ratio(0, 0), None);
+        assert_eq!(ratio(5, 0), None);
+    }
+
+    #[test]
+    fn deserialize_known_game_modes_and_platforms() {
+        assert_eq!(
+            serde_json::from_str::<GameMode>("\"ranked\"").unwrap(),
+            GameMode::Ranked
+        );
+        assert_eq!(
+            serde_json::from_str::<PlatformFamily>("\"console\"").unwrap(),
+            PlatformFamily::Console
+        );
+    }
+
+    #[traced_test]
+    #[test]
+    fn deserialize_unknown_game_modes_and_platforms() {
+        assert_eq!(
+            serde_json::from_str::<GameMode>("\"arcade\"").unwrap(),
+            GameMode::Unknown
+        );
+        assert!(logs_contain("Unknown value 'arcade'"));
+        assert_eq!(GameMode::Unknown.to_string(), "Unknown");
+
+        assert_eq!(
+            serde_json::from_str::<PlatformFamily>("\"cloud\"").unwrap(),
+            PlatformFamily::Unknown
+        );
+        assert!(logs_contain("Unknown value 'cloud'"));
+        assert_eq!(PlatformFamily::Unknown.to_string(), "Unknown");
+    }
+
+    #[test]
+    fn ranked_v2_with_unknown_board() {
+        let content = read_to_string("../samples/full_profile.json")
+            .unwrap()
+            .replace("\"warmup\"", "\"arcade\"");
+        let response: RankedV2Response = serde_json::from_str(content.as_str()).unwrap();
+
+        let platforms = response.get_for_platform(PlatformFamily::Pc).unwrap();
+        assert!(platforms.get_by_playtype(GameMode::Unknown).is_some());
+        assert!(platforms.get_by_playtype(GameMode::Warmup).is_none());
     }
 
     #[test]
@@ -445,7 +555,23 @@ mod test {
 
         assert_eq!(outcomes.total_matches(), 22);
         assert_eq!(outcomes.total_matches_with_abandons(), 23);
-        assert_eq!(outcomes.win_rate(), 0.5454545454545454);
-        assert_eq!(outcomes.win_rate_with_abandons(), 0.5217391304347826);
+        assert_eq!(outcomes.win_rate(), Some(0.5454545454545454));
+        assert_eq!(outcomes.win_rate_with_abandons(), Some(0.5217391304347826));
+    }
+
+    #[test]
+    fn win_rates_without_matches() {
+        let outcomes = MatchOutcomes::default();
+        assert_eq!(outcomes.win_rate(), None);
+        assert_eq!(outcomes.win_rate_with_abandons(), None);
+
+        // Only abandoned matches.
+        let outcomes = MatchOutcomes {
+            abandons: 2,
+            losses: 0,
+            wins: 0,
+        };
+        assert_eq!(outcomes.win_rate(), None);
+        assert_eq!(outcomes.win_rate_with_abandons(), Some(0.0));
     }
 }
@@ -1,8 +1,11 @@
 use derive_getters::Getters;
 use serde::Deserialize;
-use strum::Display;
+use strum::{Display, EnumIter, EnumString};
 use uuid::Uuid;
 
+/// Prefix used by Ubisoft for all Rainbow Six Siege instances in the game status.
+pub const SIEGE_TITLE: &str = "Rainbow Six Siege";
+
 #[derive(Debug, Clone, Deserialize, Getters)]
 #[serde(rename_all = "PascalCase")]
 pub struct GameStatus {
@@ -23,15 +26,36 @@ pub struct GameStatus {
 }
 
 /// Represents the different statuses. This is not an exhausted list, as
-/// there is no documentation from Ubisoft on the actual options.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Display)]
+/// there is no documentation from Ubisoft on the actual options, so any
+/// other status is kept as `Unknown` with the raw value.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, EnumString)]
+#[serde(from = "String")]
 pub enum Status {
     Online,
     Degraded,
     Interrupted,
+    #[strum(disabled)]
+    Unknown(String),
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Self {
+        super::mappers::parse_known(&value).unwrap_or(Self::Unknown(value))
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Online => write!(f, "Online"),
+            Status::Degraded => write!(f, "Degraded"),
+            Status::Interrupted => write!(f, "Interrupted"),
+            Status::Unknown(value) => write!(f, "{value}"),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Display, EnumString, EnumIter)]
 pub enum Platform {
     Android,
     #[serde(rename = "GEFORCE NOW")]
@@ -60,6 +84,8 @@ pub enum Platform {
 
 #[cfg(test)]
 mod test {
+    use tracing_test::traced_test;
+
     use super::*;
 
     #[test]
@@ -73,4 +99,20 @@ mod test {
         let content = std::fs::read_to_string("../samples/game-status-with-error.json").unwrap();
         let _: Vec<GameStatus> = serde_json::from_str(content.as_str()).unwrap();
     }
+
+    #[traced_test]
+    #[test]
+    fn parse_unknown_status() {
+        let content =
+            std::fs::read_to_string("../samples/game-status-with-unknown-status.json").unwrap();
+        let status: Vec<GameStatus> = serde_json::from_str(content.as_str()).unwrap();
+
+        assert_eq!(*status[0].status(), Status::Online);
+        assert_eq!(
+            *status[1].status(),
+            Status::Unknown("Maintenance".to_string())
+        );
+        assert_eq!(status[1].status().to_string(), "Maintenance");
+        assert!(logs_contain("Unknown value 'Maintenance'"));
+    }
 }
@@ -0,0 +1,162 @@
+//! Metrics derived from the raw counts and rates in [`Statistics`].
+//!
+//! The `playerstats` endpoint returns counts (kills, trades, ...) and a few
+//! per round rates. The metrics here combine them into numbers which are
+//! easier to compare between players and operators.
+
+use derive_getters::Getters;
+
+use super::{ratio, Statistics};
+
+/// Average kills per round of a player, used to normalize the rating.
+const AVERAGE_KILLS_PER_ROUND: f64 = 0.7;
+/// Average share of rounds a player survives, used to normalize the rating.
+const AVERAGE_SURVIVAL_RATE: f64 = 0.3;
+/// Average share of rounds with two or more kills, used to normalize the rating.
+const AVERAGE_MULTI_KILL_RATE: f64 = 0.15;
+
+/// Metrics which are undefined, e.g. the entry success without any opening
+/// duels, are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Getters)]
+pub struct DerivedMetrics {
+    /// Rating modelled after the HLTV 1.0 rating, where 1.0 is an average
+    /// player:
+    ///
+    /// `(KPR / 0.7 + 0.7 * survived / 0.3 + multi kill rounds / 0.15) / 2.7`
+    ///
+    /// HLTV weighs every multi kill by its size, which is not available, so
+    /// the share of rounds with a multi kill is used instead.
+    rating: Option<f64>,
+    /// Share of opening duels which did not leave the team a player down,
+    /// i.e. the opening kills plus the opening deaths which were traded:
+    ///
+    /// `(opening kills + opening death trades) / (opening kills + opening deaths)`
+    entry_success: Option<f64>,
+    /// Share of the won rounds which the player clutched:
+    ///
+    /// `rounds with a clutch * rounds played / rounds won`
+    clutch_rate: Option<f64>,
+    /// Share of kills which traded the death of a teammate, including opening
+    /// kills which were traded:
+    ///
+    /// `(trades + opening kill trades) / kills`
+    trade_efficiency: Option<f64>,
+    /// `assists / rounds played`
+    assists_per_round: Option<f64>,
+    /// `headshots / kills`
+    headshot_percentage: Option<f64>,
+}
+
+impl From<&Statistics> for DerivedMetrics {
+    fn from(statistics: &Statistics) -> Self {
+        let rating = ratio(*statistics.kills(), *statistics.rounds_played()).map(|kpr| {
+            (kpr / AVERAGE_KILLS_PER_ROUND
+                + 0.7 * *statistics.rounds_survived() / AVERAGE_SURVIVAL_RATE
+                + *statistics.rounds_with_multi_kill() / AVERAGE_MULTI_KILL_RATE)
+                / 2.7
+        });
+        let clutch_rate = ratio(*statistics.rounds_played(), *statistics.rounds_won())
+            .map(|x| *statistics.rounds_with_clutch() * x);
+
+        Self {
+            rating,
+            entry_success: ratio(
+                *statistics.opening_kills() + *statistics.opening_death_trades(),
+                *statistics.opening_kills() + *statistics.opening_deaths(),
+            ),
+            clutch_rate,
+            trade_efficiency: ratio(
+                *statistics.trades() + *statistics.opening_kill_trades(),
+                *statistics.kills(),
+            ),
+            assists_per_round: ratio(*statistics.assists(), *statistics.rounds_played()),
+            headshot_percentage: ratio(*statistics.headshots(), *statistics.kills()),
+        }
+    }
+}
+
+impl Statistics {
+    /// Calculate the metrics derived from these statistics.
+    pub fn derived_metrics(&self) -> DerivedMetrics {
+        DerivedMetrics::from(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        models::{GameModeOrAll, StatisticResponse},
+        operator::Operator,
+    };
+
+    use super::*;
+
+    fn operator_metrics(operator: Operator) -> DerivedMetrics {
+        let content = std::fs::read_to_string("../samples/operators.json").unwrap();
+        let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
+
+        stats
+            .get_operator(operator, GameModeOrAll::All)
+            .unwrap()
+            .statistics()
+            .derived_metrics()
+    }
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn derived_metrics_for_ying() {
+        // 250 rounds, 120 won, 249 kills, 50 assists, 87 headshots, 14 trades,
+        // 19 opening kills and 9 opening deaths.
+        let metrics = operator_metrics(Operator::Ying);
+
+        assert_close(
+            *metrics.rating(),
+            (0.996 / 0.7 + 0.7 * 0.372 / 0.3 + 0.26 / 0.15) / 2.7,
+        );
+        assert_close(*metrics.entry_success(), 19.0 / 28.0);
+        assert_close(*metrics.clutch_rate(), 0.024 * 250.0 / 120.0);
+        assert_close(*metrics.trade_efficiency(), 14.0 / 249.0);
+        assert_close(*metrics.assists_per_round(), 50.0 / 250.0);
+        assert_close(*metrics.headshot_percentage(), 87.0 / 249.0);
+    }
+
+    #[test]
+    fn headshot_percentage_matches_reported_accuracy() {
+        let content = std::fs::read_to_string("../samples/operators.json").unwrap();
+        let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
+
+        stats
+            .get_operators(GameModeOrAll::All, crate::models::SideOrAll::All)
+            .iter()
+            .filter(|x| *x.statistics().kills() > 0)
+            .for_each(|x| {
+                let metrics = x.statistics().derived_metrics();
+                assert!(
+                    (metrics.headshot_percentage().unwrap() - x.statistics().headshot_accuracy())
+                        .abs()
+                        < 1e-3,
+                    "{}",
+                    x.name()
+                );
+            });
+    }
+
+    #[test]
+    fn derived_metrics_without_opening_duels() {
+        // Hibana has 3 rounds with 2 kills, but no opening duels, assists or trades.
+        let metrics = operator_metrics(Operator::Hibana);
+
+        assert_eq!(*metrics.entry_success(), None);
+        assert_eq!(*metrics.assists_per_round(), Some(0.0));
+        assert_eq!(*metrics.headshot_percentage(), Some(0.0));
+        assert_eq!(*metrics.trade_efficiency(), Some(0.0));
+        assert!(metrics.rating().unwrap().is_finite());
+    }
+}
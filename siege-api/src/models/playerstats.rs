@@ -15,22 +15,29 @@ pub enum SideOrAll {
     Defender,
 }
 
-impl From<Side> for SideOrAll {
-    fn from(value: Side) -> Self {
+/// Fails for an unknown side, which is returned as the error.
+impl TryFrom<Side> for SideOrAll {
+    type Error = Side;
+
+    fn try_from(value: Side) -> Result<Self, Self::Error> {
         match value {
-            Side::Attacker => Self::Attacker,
-            Side::Defender => Self::Defender,
+            Side::Attacker => Ok(Self::Attacker),
+            Side::Defender => Ok(Self::Defender),
+            Side::Unknown => Err(value),
         }
     }
 }
 
+/// Game modes the `playerstats` endpoint aggregates statistics for.
 #[derive(
     Debug, Default, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString, strum::EnumIter,
 )]
-pub enum AllOrRanked {
+pub enum GameModeOrAll {
     #[default]
     All,
     Ranked,
+    Casual,
+    Unranked,
 }
 
 #[derive(Debug, Deserialize, Getters)]
@@ -53,18 +60,21 @@ impl StatisticResponse {
     ///
     /// # Arguments
     ///
-    /// * `game_mode` - The game mode to get data for. Currently only `all` and `ranked` is supported.
+    /// * `game_mode` - The game mode to get data for. Modes the player has not played are `None`.
     /// * `role` - Side to get statistics for.
     ///
     /// NOTE: Currently only the statistics from PC is returned.
     pub fn get_statistics_from_side(
         &self,
-        game_mode: AllOrRanked,
+        game_mode: GameModeOrAll,
         role: SideOrAll,
     ) -> Option<&Vec<GeneralStatistics>> {
+        let game_modes = &self.platforms.pc.game_modes;
         let roles = match game_mode {
-            AllOrRanked::All => self.platforms.pc.game_modes.all.as_ref(),
-            AllOrRanked::Ranked => self.platforms.pc.game_modes.ranked.as_ref(),
+            GameModeOrAll::All => game_modes.all.as_ref(),
+            GameModeOrAll::Ranked => game_modes.ranked.as_ref(),
+            GameModeOrAll::Casual => game_modes.casual.as_ref(),
+            GameModeOrAll::Unranked => game_modes.unranked.as_ref(),
         };
 
         roles.map(|x| match role {
@@ -75,19 +85,19 @@ impl StatisticResponse {
     }
 
     /// Utility method to help extract specific statistics types from self.
-    fn get_statistics<T, F>(&self, game_mode: AllOrRanked, side: SideOrAll, filter: F) -> Vec<&T>
+    fn get_statistics<T, F>(&self, game_mode: GameModeOrAll, side: SideOrAll, filter: F) -> Vec<&T>
     where
         F: Fn(&GeneralStatistics) -> Option<&T>,
     {
         self.get_statistics_from_side(game_mode, side)
             .map(|x| x.iter().filter_map(filter).collect())
-            .unwrap_or_else(Vec::default)
+            .unwrap_or_default()
     }
 
     /// Extract all operators from this side.
     pub fn get_operators(
         &self,
-        game_mode: AllOrRanked,
+        game_mode: GameModeOrAll,
         side: SideOrAll,
     ) -> Vec<&OperatorStatistics> {
         self.get_statistics(game_mode, side, |x| match x {
@@ -97,7 +107,7 @@ impl StatisticResponse {
     }
 
     /// Get all maps statistics for a given side.
-    pub fn get_maps(&self, game_mode: AllOrRanked, side: SideOrAll) -> Vec<&MapStatistics> {
+    pub fn get_maps(&self, game_mode: GameModeOrAll, side: SideOrAll) -> Vec<&MapStatistics> {
         self.get_statistics(game_mode, side, |x| match x {
             GeneralStatistics::Maps(map) => Some(map),
             _ => None,
@@ -111,7 +121,7 @@ impl StatisticResponse {
     pub fn get_operator(
         &self,
         operator: Operator,
-        game_mode: AllOrRanked,
+        game_mode: GameModeOrAll,
     ) -> Option<&OperatorStatistics> {
         // Can always use `All` here, as all operators are always included here.
         self.get_operators(game_mode, SideOrAll::All)
@@ -120,14 +130,63 @@ impl StatisticResponse {
             .copied()
     }
 
-    /// Get statistics for a given map.
-    /// Note that this will currently always get statistics in all modes and for both the defenders and attackers side.
-    pub fn get_map(&self, map_name: Map, game_mode: AllOrRanked) -> Option<&MapStatistics> {
-        self.get_maps(game_mode, SideOrAll::All)
+    /// Get statistics for a given map, for both the defenders and attackers side.
+    pub fn get_map(&self, map_name: Map, game_mode: GameModeOrAll) -> Option<&MapStatistics> {
+        self.get_map_by_side(map_name, game_mode, SideOrAll::All)
+    }
+
+    /// Get statistics for a given map on one side, or both with `SideOrAll::All`.
+    pub fn get_map_by_side(
+        &self,
+        map_name: Map,
+        game_mode: GameModeOrAll,
+        side: SideOrAll,
+    ) -> Option<&MapStatistics> {
+        self.get_maps(game_mode, side)
             .iter()
             .find(|map| *map.name() == map_name)
             .copied()
     }
+
+    /// Sum the rounds, kills and deaths of all operators or maps on a side.
+    ///
+    /// Every round is played on a single map with a single operator, so this
+    /// gives the totals for the side regardless of the type of statistics.
+    pub fn get_side_totals(&self, game_mode: GameModeOrAll, side: SideOrAll) -> SideTotals {
+        self.get_statistics(game_mode, side, |x| match x {
+            GeneralStatistics::Operator(op) => Some(&op.statistics),
+            GeneralStatistics::Maps(map) => Some(&map.statistics),
+            GeneralStatistics::Summary(_) => None,
+        })
+        .iter()
+        .fold(SideTotals::default(), |acc, x| SideTotals {
+            rounds_played: acc.rounds_played + x.rounds_played,
+            rounds_won: acc.rounds_won + x.rounds_won,
+            kills: acc.kills + x.kills,
+            deaths: acc.deaths + x.deaths,
+        })
+    }
+}
+
+/// Totals for all rounds played on a side.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Getters)]
+pub struct SideTotals {
+    rounds_played: u64,
+    rounds_won: u64,
+    kills: u64,
+    deaths: u64,
+}
+
+impl SideTotals {
+    /// Calculate the win rate of rounds, between 0 and 1. Undefined without any rounds.
+    pub fn rounds_win_rate(&self) -> Option<f64> {
+        ratio(self.rounds_won, self.rounds_played)
+    }
+
+    /// Calculate the kill/death ratio. Undefined without any deaths.
+    pub fn kd(&self) -> Option<f64> {
+        ratio(self.kills, self.deaths)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -146,8 +205,9 @@ struct OperatorResponsePlatform {
 #[serde(rename_all = "camelCase")]
 struct GameModes {
     all: Option<Mode>,
-    #[allow(dead_code)]
     ranked: Option<Mode>,
+    casual: Option<Mode>,
+    unranked: Option<Mode>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -277,18 +337,19 @@ pub struct Statistics {
 }
 
 impl Statistics {
-    pub fn opening_win_rate(&self) -> f64 {
-        self.opening_kills as f64 / (self.opening_kills + self.opening_deaths).max(1) as f64
+    /// Calculate the share of opening duels won, between 0 and 1. Undefined without any opening duels.
+    pub fn opening_win_rate(&self) -> Option<f64> {
+        ratio(self.opening_kills, self.opening_kills + self.opening_deaths)
     }
 
-    /// Calculate the win rate of matches. This will always return a number between 0 and 1.
-    pub fn matches_win_rate(&self) -> f64 {
-        self.matches_won as f64 / self.matches_played as f64
+    /// Calculate the win rate of matches, between 0 and 1. Undefined without any matches.
+    pub fn matches_win_rate(&self) -> Option<f64> {
+        ratio(self.matches_won, self.matches_played)
     }
 
-    /// Calculate the win rate of rounds. This will always return a number between 0 and 1.
-    pub fn rounds_win_rate(&self) -> f64 {
-        self.rounds_won as f64 / self.rounds_played as f64
+    /// Calculate the win rate of rounds, between 0 and 1. Undefined without any rounds.
+    pub fn rounds_win_rate(&self) -> Option<f64> {
+        ratio(self.rounds_won, self.rounds_played)
     }
 }
 
@@ -312,8 +373,9 @@ mod test {
 
     #[test]
     fn from_side_to_side_or_all() {
-        assert_eq!(Into::<SideOrAll>::into(Side::Attacker), SideOrAll::Attacker);
-        assert_eq!(Into::<SideOrAll>::into(Side::Defender), SideOrAll::Defender);
+        assert_eq!(SideOrAll::try_from(Side::Attacker), Ok(SideOrAll::Attacker));
+        assert_eq!(SideOrAll::try_from(Side::Defender), Ok(SideOrAll::Defender));
+        assert_eq!(SideOrAll::try_from(Side::Unknown), Err(Side::Unknown));
     }
 
     #[test]
@@ -324,11 +386,23 @@ mod test {
         let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
 
         // Assert this will return valid statistics for the given side.
-        AllOrRanked::iter().for_each(|mode| {
-            SideOrAll::iter().for_each(|side| {
-                stats.get_statistics_from_side(mode, side).unwrap();
+        [GameModeOrAll::All, GameModeOrAll::Ranked]
+            .into_iter()
+            .for_each(|mode| {
+                SideOrAll::iter().for_each(|side| {
+                    stats.get_statistics_from_side(mode, side).unwrap();
+                });
+            });
+
+        // The sample player has not played casual or unranked.
+        [GameModeOrAll::Casual, GameModeOrAll::Unranked]
+            .into_iter()
+            .for_each(|mode| {
+                assert!(stats
+                    .get_statistics_from_side(mode, SideOrAll::All)
+                    .is_none());
+                assert!(stats.get_operators(mode, SideOrAll::All).is_empty());
             });
-        });
     }
 
     #[test]
@@ -336,7 +410,7 @@ mod test {
         let content = std::fs::read_to_string("../samples/operators.json").unwrap();
         let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
 
-        use AllOrRanked::*;
+        use GameModeOrAll::*;
         // Act
         assert_eq!(stats.get_operators(All, SideOrAll::All).len(), 47);
         assert_eq!(stats.get_operators(All, SideOrAll::Defender).len(), 27);
@@ -347,12 +421,43 @@ mod test {
         assert_eq!(stats.get_operators(Ranked, SideOrAll::Attacker).len(), 2);
     }
 
+    #[test]
+    fn get_statistics_for_casual_and_unranked() {
+        let content = std::fs::read_to_string("../samples/operators.json")
+            .unwrap()
+            .replace("\"ranked\":", "\"casual\":");
+        let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
+
+        assert!(stats
+            .get_operators(GameModeOrAll::Ranked, SideOrAll::All)
+            .is_empty());
+        assert_eq!(
+            stats
+                .get_operators(GameModeOrAll::Casual, SideOrAll::All)
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn game_mode_or_all_display() {
+        use strum::IntoEnumIterator;
+
+        assert_eq!(
+            GameModeOrAll::iter()
+                .map(|mode| mode.to_string())
+                .collect::<Vec<_>>(),
+            vec!["All", "Ranked", "Casual", "Unranked"]
+        );
+        assert_eq!(GameModeOrAll::default(), GameModeOrAll::All);
+    }
+
     #[test]
     fn get_all_maps() {
         let content = std::fs::read_to_string("../samples/maps.json").unwrap();
         let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
 
-        use AllOrRanked::*;
+        use GameModeOrAll::*;
         // Act
         assert_eq!(stats.get_maps(All, SideOrAll::All).len(), 23);
         assert_eq!(stats.get_maps(All, SideOrAll::Defender).len(), 22);
@@ -369,7 +474,7 @@ mod test {
         let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
 
         let operator = stats
-            .get_operator(Operator::Hibana, AllOrRanked::All)
+            .get_operator(Operator::Hibana, GameModeOrAll::All)
             .unwrap();
 
         assert_eq!(operator.name, Operator::Hibana);
@@ -381,23 +486,90 @@ mod test {
         let content = std::fs::read_to_string("../samples/maps.json").unwrap();
         let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
 
-        let map = stats.get_map(Map::Yacht, AllOrRanked::All).unwrap();
+        let map = stats.get_map(Map::Yacht, GameModeOrAll::All).unwrap();
 
         assert_eq!(map.name, Map::Yacht);
         assert_eq!(*map.statistics.matches_played(), 20);
     }
 
+    #[test]
+    fn get_map_by_side() {
+        let content = std::fs::read_to_string("../samples/maps.json").unwrap();
+        let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
+
+        let all = stats.get_map(Map::Oregon, GameModeOrAll::All).unwrap();
+        let attacker = stats
+            .get_map_by_side(Map::Oregon, GameModeOrAll::All, SideOrAll::Attacker)
+            .unwrap();
+        let defender = stats
+            .get_map_by_side(Map::Oregon, GameModeOrAll::All, SideOrAll::Defender)
+            .unwrap();
+
+        assert_eq!(attacker.name, Map::Oregon);
+        assert_eq!(
+            attacker.statistics.rounds_played + defender.statistics.rounds_played,
+            all.statistics.rounds_played
+        );
+        assert_ne!(
+            attacker.statistics.rounds_won,
+            defender.statistics.rounds_won
+        );
+    }
+
+    #[test]
+    fn get_side_totals() {
+        let content = std::fs::read_to_string("../samples/operators.json").unwrap();
+        let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
+
+        let all = stats.get_side_totals(GameModeOrAll::All, SideOrAll::All);
+        let attacker = stats.get_side_totals(GameModeOrAll::All, SideOrAll::Attacker);
+        let defender = stats.get_side_totals(GameModeOrAll::All, SideOrAll::Defender);
+
+        assert!(attacker.rounds_played > 0);
+        assert_eq!(
+            attacker.rounds_played + defender.rounds_played,
+            all.rounds_played
+        );
+        assert_eq!(attacker.kills + defender.kills, all.kills);
+        assert!((0.0..=1.0).contains(&attacker.rounds_win_rate().unwrap()));
+        assert_eq!(SideTotals::default().rounds_win_rate(), None);
+        assert_eq!(SideTotals::default().kd(), None);
+    }
+
     #[test]
     fn statistics_win_rates() {
         let content = std::fs::read_to_string("../samples/operators.json").unwrap();
         let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
         let operator = stats
-            .get_operator(Operator::Ying, AllOrRanked::All)
+            .get_operator(Operator::Ying, GameModeOrAll::All)
+            .unwrap();
+
+        assert_eq!(
+            operator.statistics().opening_win_rate(),
+            Some(0.6785714285714286)
+        );
+        assert_eq!(
+            operator.statistics().matches_win_rate(),
+            Some(0.5657894736842105)
+        );
+        assert_eq!(operator.statistics().rounds_win_rate(), Some(0.48));
+    }
+
+    #[test]
+    fn statistics_win_rates_without_rounds() {
+        let content = std::fs::read_to_string("../samples/operators.json").unwrap();
+        let content = content
+            .replace("\"matchesPlayed\": 3,", "\"matchesPlayed\": 0,")
+            .replace("\"roundsPlayed\": 3,", "\"roundsPlayed\": 0,");
+        let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
+        let operator = stats
+            .get_operator(Operator::Hibana, GameModeOrAll::All)
             .unwrap();
 
-        assert_eq!(operator.statistics().opening_win_rate(), 0.6785714285714286);
-        assert_eq!(operator.statistics().matches_win_rate(), 0.5657894736842105);
-        assert_eq!(operator.statistics().rounds_win_rate(), 0.48);
+        // Hibana has no opening duels, and no matches or rounds after the replace.
+        assert_eq!(operator.statistics().opening_win_rate(), None);
+        assert_eq!(operator.statistics().matches_win_rate(), None);
+        assert_eq!(operator.statistics().rounds_win_rate(), None);
     }
 
     #[test]
@@ -449,7 +621,7 @@ mod test {
 
         match statistic {
             GeneralStatistics::Summary(_) => {}
-            _ => assert!(false),
+            _ => unreachable!(),
         }
     }
 
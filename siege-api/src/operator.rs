@@ -1,9 +1,9 @@
 use serde::Deserialize;
 use strum::{Display, EnumIter, EnumString};
 
-pub use crate::data::operator::get_operator_details;
+pub use crate::data::{loadout::get_loadout, operator::get_operator_details};
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, EnumString, Display, EnumIter)]
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash, EnumString, Display, EnumIter)]
 pub enum Operator {
     Ram,
     Fenrir,
@@ -1,9 +1,28 @@
+use std::{sync::Arc, time::Duration};
+
 use async_trait::async_trait;
-use serenity::{builder::CreateApplicationCommand, model::prelude::command::CommandOptionType};
+use serenity::{
+    builder::CreateApplicationCommand,
+    model::{
+        prelude::{
+            command::CommandOptionType, interaction::application_command::CommandDataOptionValue,
+        },
+        user::User,
+        Permissions,
+    },
+};
+use siege_api::client::SiegeClient;
 use strum::IntoEnumIterator;
 use thiserror::Error;
+use uuid::Uuid;
 
-use crate::constants::{GAME_MODE, USER};
+use crate::{
+    config::Defaults,
+    constants::{GAME_MODE, PLAYERS, USER},
+    guild_settings::guild_defaults,
+    siege_player_lookup::SiegePlayerLookup,
+    SiegeApi,
+};
 
 use self::{
     context::DiscordContext,
@@ -18,12 +37,32 @@ pub mod discord_app_command;
 pub mod game_status;
 pub mod id;
 pub mod map;
+pub mod map_bans;
+pub mod notifications;
 pub mod operator;
+pub mod operator_info;
+pub mod operators_by;
 pub mod ping;
+pub mod registry;
+pub mod roulette;
+pub mod session;
+pub mod settings;
 pub mod statistics;
+pub mod sync_rank;
+pub mod team;
 
 #[async_trait]
 pub trait CommandHandler {
+    /// Name of the command, i.e. what users type after `/`.
+    const NAME: &'static str;
+
+    /// Permissions a member needs to use the command. Everyone can use the
+    /// command if `None`.
+    const PERMISSIONS: Option<Permissions> = None;
+
+    /// Time a user has to wait between using the command.
+    const COOLDOWN: Option<Duration> = None;
+
     fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand;
 
     async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
@@ -44,8 +83,10 @@ pub trait AutocompleteHandler {
 pub enum CommandError {
     #[error("command does not exists")]
     CommandNotFound,
+    #[error("command does not support autocomplete")]
+    AutocompleteNotSupported,
     #[error("internal Discord error")]
-    SerenityError(serenity::Error),
+    SerenityError(Box<serenity::Error>),
     #[error("Siege player not found")]
     SiegePlayerNotFound,
 }
@@ -58,6 +99,9 @@ trait AddUserOptionToCommand {
 
     /// Add an option to the command to specify the game mode.
     fn add_game_mode_option(&mut self) -> &mut Self;
+
+    /// Add options to the command to specify up to a full team of users.
+    fn add_team_options(&mut self) -> &mut Self;
 }
 
 impl AddUserOptionToCommand for CreateApplicationCommand {
@@ -79,13 +123,124 @@ impl AddUserOptionToCommand for CreateApplicationCommand {
                 .kind(CommandOptionType::String)
                 .required(false);
 
-            siege_api::models::AllOrRanked::iter().for_each(|mode| {
+            siege_api::models::GameModeOrAll::iter().for_each(|mode| {
                 option.add_string_choice(mode, mode);
             });
 
             option
         })
     }
+
+    fn add_team_options(&mut self) -> &mut Self {
+        PLAYERS.iter().enumerate().for_each(|(i, name)| {
+            self.create_option(|option| {
+                option
+                    .name(name)
+                    .description(format!(
+                        "Player {} of the team. Defaults to the sending user",
+                        i + 1
+                    ))
+                    .kind(CommandOptionType::User)
+                    .required(false)
+            });
+        });
+
+        self
+    }
+}
+
+/// Get the users given in the team options, without duplicates. Defaults to
+/// the sending user if no users are given.
+fn get_team_users<Cmd: DiscordAppCmd>(command: &Cmd) -> Vec<User> {
+    let mut users: Vec<User> = Vec::new();
+    PLAYERS
+        .iter()
+        .filter_map(|name| match command.get_option(name) {
+            Some(CommandDataOptionValue::User(user, _)) => Some(user),
+            _ => None,
+        })
+        .for_each(|user| {
+            if !users.iter().any(|x| x.id == user.id) {
+                users.push(user);
+            }
+        });
+
+    if users.is_empty() {
+        users.push(command.get_user_from_command_or_default());
+    }
+
+    users
+}
+
+/// Join the names of the users into a comma separated list.
+fn join_names<'a>(users: impl IntoIterator<Item = &'a User>) -> String {
+    users
+        .into_iter()
+        .map(|user| user.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Find the Siege players linked to the users given in the team options.
+///
+/// Returns the users with their Siege player ID, and the users which have not
+/// linked a Siege player.
+async fn lookup_team<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> (Vec<(User, Uuid)>, Vec<User>)
+where
+    Ctx: DiscordContext + Send + Sync,
+    Cmd: DiscordAppCmd,
+{
+    let data = ctx.data().read().await;
+    let lookup = data
+        .get::<SiegePlayerLookup>()
+        .expect("always registered")
+        .read()
+        .await;
+
+    let (found, missing): (Vec<_>, Vec<_>) = get_team_users(command)
+        .into_iter()
+        .map(|user| {
+            let player_id = lookup.get(&user.id).copied();
+            (user, player_id)
+        })
+        .partition(|(_, player_id)| player_id.is_some());
+
+    (
+        found
+            .into_iter()
+            .filter_map(|(user, player_id)| player_id.map(|id| (user, id)))
+            .collect(),
+        missing.into_iter().map(|(user, _)| user).collect(),
+    )
+}
+
+/// Get the client for the Siege API. Requests to Ubisoft can take longer than
+/// Discord waits for a response, so the response is deferred first.
+async fn siege_client<Ctx, Cmd>(
+    ctx: &Ctx,
+    command: &Cmd,
+) -> Result<Arc<dyn SiegeClient>, CommandError>
+where
+    Ctx: DiscordContext,
+    Cmd: DiscordAppCmd,
+{
+    command.defer(ctx.http()).await?;
+
+    let data = ctx.data().read().await;
+    Ok(data
+        .get::<SiegeApi>()
+        .expect("Siege client is always registered")
+        .clone())
+}
+
+/// Get the options used when they are not given to a command, changed by the
+/// settings of the guild the command is run in.
+pub(crate) async fn defaults<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> Defaults
+where
+    Ctx: DiscordContext,
+    Cmd: DiscordAppCmd,
+{
+    guild_defaults(&*ctx.data().read().await, command.guild_id()).await
 }
 
 #[cfg(test)]
@@ -99,14 +254,17 @@ pub(crate) mod test {
     };
     use uuid::Uuid;
 
-    use crate::SiegeApi;
+    use crate::{
+        config::Defaults,
+        pagination::{PaginationSessions, Paginations},
+        SiegeApi,
+    };
 
     use super::context::MockDiscordContext;
 
     mockall::mock! {
         pub SiegeClient {}
 
-        #[allow(implied_bounds_entailment)]
         #[async_trait]
         impl siege_api::client::SiegeClient for SiegeClient {
             async fn search_for_player(&self, name: &str) -> siege_api::client::Result<Uuid>;
@@ -114,7 +272,7 @@ pub(crate) mod test {
             async fn get_full_profiles(&self, player_id: Uuid) -> siege_api::client::Result<RankedV2Response>;
             async fn get_operators(&self, player_id: Uuid) -> siege_api::client::Result<StatisticResponse>;
             async fn get_maps(&self, player_id: Uuid) -> siege_api::client::Result<StatisticResponse>;
-            async fn siege_status(&self) -> siege_api::client::Result<Vec<GameStatus>>;
+            async fn siege_status(&self, title: Option<String>) -> siege_api::client::Result<Vec<GameStatus>>;
         }
     }
 
@@ -136,6 +294,8 @@ pub(crate) mod test {
         {
             let mut data = data.write().await;
             data.insert::<SiegeApi>(Arc::new(client));
+            data.insert::<Paginations>(Arc::new(RwLock::new(PaginationSessions::default())));
+            data.insert::<Defaults>(Defaults::default());
         }
         ctx.expect_data().return_const(data);
     }
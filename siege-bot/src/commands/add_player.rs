@@ -6,10 +6,11 @@ use serenity::{
     },
 };
 
-use crate::{siege_player_lookup::SiegePlayerLookup, SiegeApi};
+use crate::siege_player_lookup::SiegePlayerLookup;
 
 use super::{
-    context::DiscordContext, discord_app_command::DiscordAppCmd, CmdResult, CommandHandler,
+    context::DiscordContext, discord_app_command::DiscordAppCmd, siege_client, CmdResult,
+    CommandHandler,
 };
 
 pub struct AddPlayerCommand;
@@ -18,9 +19,11 @@ static UBISOFT_NAME: &str = "ubisoft_name";
 
 #[async_trait]
 impl CommandHandler for AddPlayerCommand {
+    const NAME: &'static str = "add";
+
     fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
         command
-            .name("add")
+            .name(Self::NAME)
             .description("Link your Ubisoft player ID to your Discord Id. This is required before using most commands")
             .create_option(|option| {
                 option
@@ -52,8 +55,7 @@ impl CommandHandler for AddPlayerCommand {
         tracing::info!("Linking {} with Ubisoft account {name}", user.tag());
 
         let ubisoft_id = {
-            let data = ctx.data().read().await;
-            let siege_client = data.get::<SiegeApi>().expect("client always registered");
+            let siege_client = siege_client(ctx, command).await?;
             match siege_client.search_for_player(&name).await {
                 Ok(id) => id,
                 Err(err) => {
@@ -157,6 +159,7 @@ mod test {
 
         // Arrange command
         let mut command = MockDiscordAppCmd::new();
+        command.expect_defer().once().returning(|_| Ok(()));
         command
             .expect_get_option()
             .with(eq(UBISOFT_NAME))
@@ -207,6 +210,7 @@ mod test {
 
         // Arrange command
         let mut command = MockDiscordAppCmd::new();
+        command.expect_defer().once().returning(|_| Ok(()));
         command
             .expect_get_option()
             .with(eq(UBISOFT_NAME))
@@ -251,6 +255,7 @@ mod test {
 
         // Arrange command
         let mut command = MockDiscordAppCmd::new();
+        command.expect_defer().once().returning(|_| Ok(()));
         command
             .expect_get_option()
             .with(eq(UBISOFT_NAME))
@@ -6,14 +6,18 @@ use serenity::{
     },
     utils::Color,
 };
-use siege_api::models::{AllOrRanked, MapStatistics, SideOrAll};
+use siege_api::models::{MapStatistics, SideOrAll};
 use strum::IntoEnumIterator;
 
-use crate::{constants::GAME_MODE, formatting::FormatEmbedded, SiegeApi};
+use crate::{
+    constants::GAME_MODE,
+    pagination::{paginate, send_pages},
+    rendering::map_win_rate_chart,
+};
 
 use super::{
-    context::DiscordContext, discord_app_command::DiscordAppCmd, AddUserOptionToCommand, CmdResult,
-    CommandHandler,
+    context::DiscordContext, defaults, discord_app_command::DiscordAppCmd, siege_client,
+    AddUserOptionToCommand, CmdResult, CommandHandler,
 };
 
 #[derive(Debug, Clone, Copy, strum::EnumString, strum::Display, strum::EnumIter)]
@@ -33,9 +37,11 @@ pub struct AllMapsCommand;
 
 #[async_trait]
 impl CommandHandler for AllMapsCommand {
+    const NAME: &'static str = "all_maps";
+
     fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
         command
-            .name("all_maps")
+            .name(Self::NAME)
             .description("List statistics for all maps for a given side or overall")
             .create_option(|option| {
                 option
@@ -94,7 +100,7 @@ impl CommandHandler for AllMapsCommand {
             .unwrap_or(0);
         let game_mode = command
             .extract_enum_option(GAME_MODE)
-            .unwrap_or(AllOrRanked::All);
+            .unwrap_or(defaults(ctx, command).await.game_mode);
 
         let user = command.get_user_from_command_or_default();
         tracing::info!(
@@ -105,10 +111,7 @@ impl CommandHandler for AllMapsCommand {
         let player_id = ctx.lookup_siege_player(command, &user).await?;
 
         let response = {
-            let data = ctx.data().read().await;
-            let siege_client = data
-                .get::<SiegeApi>()
-                .expect("Siege client is always registered");
+            let siege_client = siege_client(ctx, command).await?;
             match siege_client.get_maps(player_id).await {
                 Ok(data) => data,
                 Err(err) => {
@@ -127,20 +130,20 @@ impl CommandHandler for AllMapsCommand {
 
         sort(&mut maps, sorting);
 
-        command
-            .send_embedded(
-                ctx.http(),
-                CreateEmbed::default()
-                    .thumbnail(user.avatar_url().unwrap_or_default())
-                    .title(format!(
-                        "{}/{} map statistics for {}",
-                        game_mode, side, user.name
-                    ))
-                    .color(Color::TEAL)
-                    .format(&maps)
-                    .to_owned(),
-            )
-            .await
+        let chart = map_win_rate_chart(&maps);
+        let mut embed = CreateEmbed::default();
+        embed
+            .thumbnail(user.avatar_url().unwrap_or_default())
+            .title(format!(
+                "{}/{} map statistics for {}",
+                game_mode, side, user.name
+            ))
+            .color(Color::TEAL);
+        if let Some(chart) = &chart {
+            embed.attachment(&chart.filename);
+        }
+
+        send_pages(ctx, command, paginate(&embed, &maps), chart).await
     }
 }
 
@@ -183,11 +186,15 @@ fn sort(maps: &mut [&MapStatistics], sorting: Sorting) {
 
 #[cfg(test)]
 mod test {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
 
     use mockall::predicate::*;
     use serde_json::Value;
-    use serenity::model::user::User;
-    use siege_api::models::StatisticResponse;
+    use serenity::model::{prelude::UserId, user::User};
+    use siege_api::models::{GameModeOrAll, StatisticResponse};
     use uuid::Uuid;
 
     use crate::{
@@ -217,7 +224,7 @@ mod test {
 
         let options = command.0.get("options").unwrap().as_array().unwrap();
         // Assert first options
-        let opt = options.get(0).unwrap();
+        let opt = options.first().unwrap();
         assert_eq!(opt.get("name").unwrap(), SIDE);
         assert_eq!(*opt.get("required").unwrap(), Value::Bool(true));
         assert_eq!(opt.get("choices").unwrap().as_array().unwrap().len(), 3);
@@ -236,7 +243,7 @@ mod test {
         assert_eq!(opt.get("name").unwrap(), GAME_MODE);
         assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
         assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 3); // Corresponds to `CommandOptionType::String`
-        assert_eq!(opt.get("choices").unwrap().as_array().unwrap().len(), 2);
+        assert_eq!(opt.get("choices").unwrap().as_array().unwrap().len(), 4);
         assert!(!opt.get("description").unwrap().as_str().unwrap().is_empty());
 
         let opt = options.get(4).unwrap();
@@ -252,18 +259,18 @@ mod test {
         let siege_id = Uuid::new_v4();
 
         // Testing different combinations of arguments.
-        for (side, sorting, rounds, game_mode) in vec![
+        for (side, sorting, rounds, game_mode) in [
             (
                 Some(SideOrAll::All),
                 None,
-                Some(10 as i64),
-                Some(AllOrRanked::All),
+                Some(10_i64),
+                Some(GameModeOrAll::All),
             ),
             (
                 Some(SideOrAll::Attacker),
                 Some(Sorting::Kd),
                 None,
-                Some(AllOrRanked::Ranked),
+                Some(GameModeOrAll::Ranked),
             ),
             (
                 Some(SideOrAll::Defender),
@@ -306,6 +313,8 @@ mod test {
             register_client_in_type_map(&mut ctx, mock_client).await;
 
             let mut command = MockDiscordAppCmd::new();
+            command.expect_guild_id().return_const(None);
+            command.expect_defer().once().returning(|_| Ok(()));
             command
                 .expect_extract_enum_option()
                 .with(eq(SIDE))
@@ -317,24 +326,37 @@ mod test {
             command
                 .expect_get_option()
                 .with(eq(MINIMUM_ROUNDS))
-                .return_const(rounds.map(|x| CommandDataOptionValue::Integer(x)));
+                .return_const(rounds.map(CommandDataOptionValue::Integer));
             command
-                .expect_extract_enum_option::<AllOrRanked>()
+                .expect_extract_enum_option::<GameModeOrAll>()
                 .with(eq(GAME_MODE))
                 .return_const(game_mode);
             command
                 .expect_get_user_from_command_or_default()
                 .return_const(user.clone());
 
-            // Assert the right message is sent back
+            // Assert exactly one message is sent back, which has buttons if
+            // there is more than one page.
+            let sent = Arc::new(AtomicUsize::new(0));
+            let sent_embedded = sent.clone();
             command
-                .expect_send_embedded()
-                .once()
-                .with(always(), always())
-                .returning(|_, _| Ok(()));
+                .expect_send_embedded_with_attachment()
+                .returning(move |_, _, _| {
+                    sent_embedded.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                });
+            let sent_pages = sent.clone();
+            command.expect_user_id().return_const(UserId(1));
+            command
+                .expect_send_embedded_with_components()
+                .returning(move |_, _, _, _| {
+                    sent_pages.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                });
 
             // Act
             assert!(AllMapsCommand::run(&ctx, &command).await.is_ok());
+            assert_eq!(sent.load(Ordering::Relaxed), 1);
         }
     }
 
@@ -360,6 +382,8 @@ mod test {
 
         // Setup command
         let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command.expect_defer().once().returning(|_| Ok(()));
         command
             .expect_extract_enum_option()
             .with(eq(SIDE))
@@ -373,7 +397,7 @@ mod test {
             .with(eq(MINIMUM_ROUNDS))
             .return_const(None);
         command
-            .expect_extract_enum_option::<AllOrRanked>()
+            .expect_extract_enum_option::<GameModeOrAll>()
             .with(eq(GAME_MODE))
             .return_const(None);
         command
@@ -8,19 +8,19 @@ use serenity::{
 };
 use siege_api::{
     game_models::Side,
-    models::{AllOrRanked, OperatorStatistics},
+    models::{GameModeOrAll, OperatorStatistics, SideOrAll},
 };
 use strum::IntoEnumIterator;
 
 use crate::{
     constants::{GAME_MODE, MINIMUM_ROUNDS, SIDE, SORTING},
-    formatting::FormatEmbedded,
-    SiegeApi,
+    pagination::{paginate, send_pages},
+    rendering::operator_kd_chart,
 };
 
 use super::{
-    context::DiscordContext, discord_app_command::DiscordAppCmd, AddUserOptionToCommand, CmdResult,
-    CommandHandler,
+    context::DiscordContext, defaults, discord_app_command::DiscordAppCmd, siege_client,
+    AddUserOptionToCommand, CmdResult, CommandHandler,
 };
 
 #[derive(Debug, Clone, Copy, strum::EnumString, strum::Display, strum::EnumIter)]
@@ -34,9 +34,11 @@ pub struct AllOperatorCommand;
 
 #[async_trait]
 impl CommandHandler for AllOperatorCommand {
+    const NAME: &'static str = "all_operators";
+
     fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
         command
-            .name("all_operators")
+            .name(Self::NAME)
             .description("List statistics for all operators for a given side")
             .create_option(|option| {
                 option
@@ -63,7 +65,9 @@ impl CommandHandler for AllOperatorCommand {
             .create_option(|option| {
                 option
                     .name(MINIMUM_ROUNDS)
-                    .description("Ignore operators you have played for less than this limit")
+                    .description(
+                        "Ignore operators you have played for less than this limit. Defaults to the server setting",
+                    )
                     .kind(CommandOptionType::Integer)
                     .required(false)
             })
@@ -74,7 +78,7 @@ impl CommandHandler for AllOperatorCommand {
                     .kind(CommandOptionType::String)
                     .required(false);
 
-                AllOrRanked::iter().for_each(|mode| {
+                GameModeOrAll::iter().for_each(|mode| {
                     option.add_string_choice(mode, mode);
                 });
 
@@ -90,17 +94,23 @@ impl CommandHandler for AllOperatorCommand {
         let side = command
             .extract_enum_option::<Side>(SIDE)
             .expect("required argument");
+        let Ok(side_filter) = SideOrAll::try_from(side) else {
+            return command
+                .send_text(ctx.http(), &format!("Unknown side {side}"))
+                .await;
+        };
         let sorting = command.extract_enum_option(SORTING).unwrap_or(Sorting::Kd);
+        let defaults = defaults(ctx, command).await;
         let minimum_rounds = command
             .get_option(MINIMUM_ROUNDS)
             .and_then(|x| match x {
                 CommandDataOptionValue::Integer(value) => Some(value),
                 _ => None,
             })
-            .unwrap_or(0);
+            .unwrap_or(defaults.minimum_rounds as i64);
         let game_mode = command
             .extract_enum_option(GAME_MODE)
-            .unwrap_or(AllOrRanked::All);
+            .unwrap_or(defaults.game_mode);
 
         let user = command.get_user_from_command_or_default();
         tracing::info!(
@@ -111,10 +121,7 @@ impl CommandHandler for AllOperatorCommand {
         let player_id = ctx.lookup_siege_player(command, &user).await?;
 
         let operator_response = {
-            let data = ctx.data().read().await;
-            let siege_client = data
-                .get::<SiegeApi>()
-                .expect("Siege client is always registered");
+            let siege_client = siege_client(ctx, command).await?;
             match siege_client.get_operators(player_id).await {
                 Ok(data) => data,
                 Err(err) => {
@@ -125,27 +132,27 @@ impl CommandHandler for AllOperatorCommand {
         };
 
         let mut operators = operator_response
-            .get_operators(game_mode, side.into())
+            .get_operators(game_mode, side_filter)
             .iter()
             .filter(|op| *op.statistics().rounds_played() as i64 >= minimum_rounds)
             .copied()
             .collect::<Vec<_>>();
         sort(&mut operators, sorting);
 
-        command
-            .send_embedded(
-                ctx.http().clone(),
-                CreateEmbed::default()
-                    .thumbnail(user.avatar_url().unwrap_or_default())
-                    .title(format!(
-                        "{}/{} operator statistics for {}",
-                        game_mode, side, user.name
-                    ))
-                    .color(Color::TEAL)
-                    .format(&operators)
-                    .to_owned(),
-            )
-            .await
+        let chart = operator_kd_chart(&operators);
+        let mut embed = CreateEmbed::default();
+        embed
+            .thumbnail(user.avatar_url().unwrap_or_default())
+            .title(format!(
+                "{}/{} operator statistics for {}",
+                game_mode, side, user.name
+            ))
+            .color(Color::TEAL);
+        if let Some(chart) = &chart {
+            embed.attachment(&chart.filename);
+        }
+
+        send_pages(ctx, command, paginate(&embed, &operators), chart).await
     }
 }
 
@@ -177,12 +184,15 @@ fn sort(operators: &mut [&OperatorStatistics], sorting: Sorting) {
 
 #[cfg(test)]
 mod test {
-    use std::sync::Arc;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
 
     use mockall::predicate::*;
     use serde_json::Value;
     use serenity::{
-        model::user::User,
+        model::{prelude::UserId, user::User},
         prelude::{RwLock, TypeMap},
     };
     use siege_api::models::StatisticResponse;
@@ -194,8 +204,11 @@ mod test {
             discord_app_command::MockDiscordAppCmd,
             test::{register_client_in_type_map, MockSiegeClient},
         },
+        config::Defaults,
         constants::USER,
+        pagination::{PaginationSessions, Paginations},
         siege_player_lookup::{MockPlayerLookup, SiegePlayerLookup},
+        SiegeApi,
     };
 
     use super::*;
@@ -216,7 +229,7 @@ mod test {
 
         let options = command.0.get("options").unwrap().as_array().unwrap();
         // Assert first options
-        let opt = options.get(0).unwrap();
+        let opt = options.first().unwrap();
         assert_eq!(opt.get("name").unwrap(), SIDE);
         assert_eq!(*opt.get("required").unwrap(), Value::Bool(true));
         assert_eq!(opt.get("choices").unwrap().as_array().unwrap().len(), 2);
@@ -235,7 +248,7 @@ mod test {
         assert_eq!(opt.get("name").unwrap(), GAME_MODE);
         assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 3); // Corresponds to `CommandOptionType::String`
         assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
-        assert_eq!(opt.get("choices").unwrap().as_array().unwrap().len(), 2);
+        assert_eq!(opt.get("choices").unwrap().as_array().unwrap().len(), 4);
 
         let opt = options.get(4).unwrap();
         assert_eq!(opt.get("name").unwrap(), USER);
@@ -250,18 +263,18 @@ mod test {
         let siege_id = Uuid::new_v4();
 
         // Testing different combinations of arguments.
-        for (side, sorting, rounds, game_mode) in vec![
+        for (side, sorting, rounds, game_mode) in [
             (
                 Some(Side::Attacker),
                 Some(Sorting::Kd),
-                Some(10 as i64),
-                Some(AllOrRanked::All),
+                Some(10_i64),
+                Some(GameModeOrAll::All),
             ),
             (
                 Some(Side::Defender),
                 Some(Sorting::RoundsPlayed),
                 None,
-                Some(AllOrRanked::Ranked),
+                Some(GameModeOrAll::Ranked),
             ),
             (Some(Side::Defender), Some(Sorting::WinRate), None, None),
         ] {
@@ -289,10 +302,14 @@ mod test {
                 let mut data = data.write().await;
                 data.insert::<SiegeApi>(Arc::new(mock_client));
                 data.insert::<SiegePlayerLookup>(Arc::new(RwLock::new(mock_lookup)));
+                data.insert::<Defaults>(Defaults::default());
+                data.insert::<Paginations>(Arc::new(RwLock::new(PaginationSessions::default())));
             }
             ctx.expect_data().return_const(data);
 
             let mut command = MockDiscordAppCmd::new();
+            command.expect_guild_id().return_const(None);
+            command.expect_defer().once().returning(|_| Ok(()));
             command
                 .expect_extract_enum_option()
                 .with(eq(SIDE))
@@ -304,24 +321,37 @@ mod test {
             command
                 .expect_get_option()
                 .with(eq(MINIMUM_ROUNDS))
-                .return_const(rounds.map(|x| CommandDataOptionValue::Integer(x)));
+                .return_const(rounds.map(CommandDataOptionValue::Integer));
             command
-                .expect_extract_enum_option::<AllOrRanked>()
+                .expect_extract_enum_option::<GameModeOrAll>()
                 .with(eq(GAME_MODE))
                 .return_const(game_mode);
             command
                 .expect_get_user_from_command_or_default()
                 .return_const(user.clone());
 
-            // Assert the right message is sent back
+            // Assert exactly one message is sent back, which has buttons if
+            // there is more than one page.
+            let sent = Arc::new(AtomicUsize::new(0));
+            let sent_embedded = sent.clone();
             command
-                .expect_send_embedded()
-                .once()
-                .with(always(), always())
-                .returning(|_, _| Ok(()));
+                .expect_send_embedded_with_attachment()
+                .returning(move |_, _, _| {
+                    sent_embedded.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                });
+            let sent_pages = sent.clone();
+            command.expect_user_id().return_const(UserId(1));
+            command
+                .expect_send_embedded_with_components()
+                .returning(move |_, _, _, _| {
+                    sent_pages.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                });
 
             // Act
             assert!(AllOperatorCommand::run(&ctx, &command).await.is_ok());
+            assert_eq!(sent.load(Ordering::Relaxed), 1);
         }
     }
 
@@ -346,6 +376,8 @@ mod test {
         register_client_in_type_map(&mut ctx, mock_client).await;
 
         let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command.expect_defer().once().returning(|_| Ok(()));
         command
             .expect_extract_enum_option()
             .with(eq(SIDE))
@@ -359,7 +391,7 @@ mod test {
             .with(eq(MINIMUM_ROUNDS))
             .return_const(None);
         command
-            .expect_extract_enum_option::<AllOrRanked>()
+            .expect_extract_enum_option::<GameModeOrAll>()
             .with(eq(GAME_MODE))
             .return_const(None);
         command
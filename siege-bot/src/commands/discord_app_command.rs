@@ -1,15 +1,25 @@
-use std::{str::FromStr, sync::Arc};
+use std::{
+    borrow::Cow,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use async_trait::async_trait;
 use serenity::{
-    builder::{CreateAutocompleteResponse, CreateEmbed},
+    builder::{CreateAutocompleteResponse, CreateComponents, CreateEmbed},
     http::Http,
     model::{
+        prelude::command::CommandOptionType,
         prelude::interaction::{
             application_command::{ApplicationCommandInteraction, CommandDataOptionValue},
             autocomplete::AutocompleteInteraction,
+            message_component::MessageComponentInteraction,
             InteractionResponseType,
         },
+        prelude::{AttachmentType, ChannelId, GuildId, UserId},
         user::User,
     },
 };
@@ -18,14 +28,39 @@ use crate::constants::NAME;
 
 use super::{CmdResult, CommandError};
 
+/// A file attached to a response, e.g. a rendered chart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attachment {
+    pub filename: String,
+    pub data: Vec<u8>,
+}
+
+impl From<Attachment> for AttachmentType<'static> {
+    fn from(attachment: Attachment) -> Self {
+        AttachmentType::Bytes {
+            data: Cow::Owned(attachment.data),
+            filename: attachment.filename,
+        }
+    }
+}
+
 /// Wrapper for a `ApplicationCommandInteraction`.
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait DiscordAppCmd: Sync + Send {
+    /// Name of the invoked command.
+    fn command_name(&self) -> String;
+
+    /// The user who invoked the command.
+    fn user_id(&self) -> UserId;
+
     /// Extract an option from the command. If not provided, `None` will be
     /// returned.
     fn get_option(&self, name: &str) -> Option<CommandDataOptionValue>;
 
+    /// Name of the invoked subcommand, e.g. `start` for `/session start`.
+    fn subcommand(&self) -> Option<String>;
+
     /// Extract an option of the given name and parse it into the given type.
     fn extract_enum_option<T>(&self, option_name: &str) -> Option<T>
     where
@@ -35,17 +70,158 @@ pub trait DiscordAppCmd: Sync + Send {
     /// return the user who invoked the command.
     fn get_user_from_command_or_default(&self) -> User;
 
+    /// The guild the command was invoked in, if not sent as a direct message.
+    fn guild_id(&self) -> Option<GuildId>;
+
+    /// The channel the command was invoked in.
+    fn channel_id(&self) -> ChannelId;
+
+    /// Acknowledge the command and show that the bot is thinking. Discord
+    /// only waits three seconds for a response, while a deferred response can
+    /// be sent for up to 15 minutes. Responses sent afterwards edit the
+    /// deferred response.
+    async fn defer(&self, http: Option<Arc<Http>>) -> CmdResult;
+
     async fn send_text(&self, http: Option<Arc<Http>>, text: &str) -> CmdResult;
 
     async fn send_embedded(&self, http: Option<Arc<Http>>, embed: CreateEmbed) -> CmdResult;
+
+    /// Send an embed with a file, e.g. a chart, which the embed can show with
+    /// `CreateEmbed::attachment`.
+    async fn send_embedded_with_attachment(
+        &self,
+        http: Option<Arc<Http>>,
+        embed: CreateEmbed,
+        attachment: Attachment,
+    ) -> CmdResult;
+
+    /// Send an embed with message components, such as buttons, below it.
+    async fn send_embedded_with_components(
+        &self,
+        http: Option<Arc<Http>>,
+        embed: CreateEmbed,
+        components: CreateComponents,
+        attachment: Option<Attachment>,
+    ) -> CmdResult;
+}
+
+/// An `ApplicationCommandInteraction` which keeps track of whether the
+/// response has been deferred, since a deferred response has to be edited
+/// instead of created.
+pub struct CommandInteraction {
+    interaction: ApplicationCommandInteraction,
+    deferred: AtomicBool,
+    /// Only show the responses to the user who invoked the command.
+    ephemeral: AtomicBool,
+}
+
+impl From<ApplicationCommandInteraction> for CommandInteraction {
+    fn from(interaction: ApplicationCommandInteraction) -> Self {
+        Self {
+            interaction,
+            deferred: AtomicBool::new(false),
+            ephemeral: AtomicBool::new(false),
+        }
+    }
+}
+
+impl CommandInteraction {
+    /// Set whether the responses are only shown to the user who invoked the
+    /// command. Has to be set before responding.
+    pub fn set_ephemeral(&self, ephemeral: bool) {
+        self.ephemeral.store(ephemeral, Ordering::Release);
+    }
+
+    /// Create the response, or complete it if the response was deferred.
+    async fn respond(
+        &self,
+        http: Arc<Http>,
+        content: Option<&str>,
+        embed: Option<CreateEmbed>,
+        components: Option<CreateComponents>,
+        attachment: Option<Attachment>,
+    ) -> CmdResult {
+        let deferred = self.deferred.load(Ordering::Acquire);
+        let ephemeral = self.ephemeral.load(Ordering::Acquire);
+        let result = match attachment {
+            // Files cannot be added when editing the deferred response, but
+            // the first follow up message replaces it instead.
+            Some(attachment) if deferred => self
+                .interaction
+                .create_followup_message(http, |message| {
+                    if let Some(content) = content {
+                        message.content(content);
+                    }
+                    if let Some(embed) = embed {
+                        message.add_embed(embed);
+                    }
+                    if let Some(components) = components {
+                        message.set_components(components);
+                    }
+                    message.ephemeral(ephemeral).add_file(attachment)
+                })
+                .await
+                .map(|_| ()),
+            None if deferred => self
+                .interaction
+                .edit_original_interaction_response(http, |response| {
+                    if let Some(content) = content {
+                        response.content(content);
+                    }
+                    if let Some(embed) = embed {
+                        response.add_embed(embed);
+                    }
+                    if let Some(components) = components {
+                        response.set_components(components);
+                    }
+                    response
+                })
+                .await
+                .map(|_| ()),
+            _ => {
+                self.interaction
+                    .create_interaction_response(http, |response| {
+                        response
+                            .kind(InteractionResponseType::ChannelMessageWithSource)
+                            .interaction_response_data(|message| {
+                                if let Some(content) = content {
+                                    message.content(content);
+                                }
+                                if let Some(embed) = embed {
+                                    message.add_embed(embed);
+                                }
+                                if let Some(components) = components {
+                                    message.set_components(components);
+                                }
+                                if let Some(attachment) = attachment {
+                                    message.add_file(attachment);
+                                }
+                                message.ephemeral(ephemeral)
+                            })
+                    })
+                    .await
+            }
+        };
+
+        result.map_err(|err| CommandError::SerenityError(Box::new(err)))
+    }
 }
 
 /// Implementation for wrapper trait. Is mostly transparent + a utility methods
 /// to extract data.
 #[async_trait]
-impl DiscordAppCmd for ApplicationCommandInteraction {
+impl DiscordAppCmd for CommandInteraction {
+    fn command_name(&self) -> String {
+        self.interaction.data.name.clone()
+    }
+
+    fn user_id(&self) -> UserId {
+        self.interaction.user.id
+    }
+
     fn get_option(&self, name: &str) -> Option<CommandDataOptionValue> {
-        self.data
+        self.interaction
+            .data
             .options
             .iter()
             .find(|x| x.name == name)
@@ -53,6 +229,15 @@ impl DiscordAppCmd for ApplicationCommandInteraction {
             .cloned()
     }
 
+    fn subcommand(&self) -> Option<String> {
+        self.interaction
+            .data
+            .options
+            .iter()
+            .find(|x| x.kind == CommandOptionType::SubCommand)
+            .map(|x| x.name.clone())
+    }
+
     fn extract_enum_option<T>(&self, option_name: &str) -> Option<T>
     where
         T: FromStr + 'static,
@@ -72,39 +257,103 @@ impl DiscordAppCmd for ApplicationCommandInteraction {
                 CommandDataOptionValue::User(user, _) => Some(user),
                 _ => None,
             })
-            .unwrap_or(self.user.clone())
+            .unwrap_or(self.interaction.user.clone())
+    }
+
+    fn guild_id(&self) -> Option<GuildId> {
+        self.interaction.guild_id
+    }
+
+    fn channel_id(&self) -> ChannelId {
+        self.interaction.channel_id
+    }
+
+    async fn defer(&self, http: Option<Arc<Http>>) -> CmdResult {
+        if self.deferred.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        // The response is shown to everyone unless the deferred response is
+        // ephemeral, so the flag is set here instead of in `respond`.
+        let ephemeral = self.ephemeral.load(Ordering::Acquire);
+        self.interaction
+            .create_interaction_response(
+                http.expect("http should always be set when deferring"),
+                |response| {
+                    response
+                        .kind(InteractionResponseType::DeferredChannelMessageWithSource)
+                        .interaction_response_data(|message| message.ephemeral(ephemeral))
+                },
+            )
+            .await
+            .map_err(|err| CommandError::SerenityError(Box::new(err)))?;
+        self.deferred.store(true, Ordering::Release);
+
+        Ok(())
     }
 
     async fn send_text(&self, http: Option<Arc<Http>>, text: &str) -> CmdResult {
-        self.create_interaction_response(
+        self.respond(
             http.expect("http should always be set when sending text"),
-            |response| {
-                response
-                    .kind(InteractionResponseType::ChannelMessageWithSource)
-                    .interaction_response_data(|message| message.content(text))
-            },
+            Some(text),
+            None,
+            None,
+            None,
         )
         .await
-        .map_err(CommandError::SerenityError)
     }
 
     async fn send_embedded(&self, http: Option<Arc<Http>>, embed: CreateEmbed) -> CmdResult {
-        self.create_interaction_response(
+        self.respond(
             http.expect("http should always be set when sending embedded"),
-            |response| {
-                response
-                    .kind(InteractionResponseType::ChannelMessageWithSource)
-                    .interaction_response_data(|msg| msg.add_embed(embed))
-            },
+            None,
+            Some(embed),
+            None,
+            None,
+        )
+        .await
+    }
+
+    async fn send_embedded_with_attachment(
+        &self,
+        http: Option<Arc<Http>>,
+        embed: CreateEmbed,
+        attachment: Attachment,
+    ) -> CmdResult {
+        self.respond(
+            http.expect("http should always be set when sending embedded"),
+            None,
+            Some(embed),
+            None,
+            Some(attachment),
+        )
+        .await
+    }
+
+    async fn send_embedded_with_components(
+        &self,
+        http: Option<Arc<Http>>,
+        embed: CreateEmbed,
+        components: CreateComponents,
+        attachment: Option<Attachment>,
+    ) -> CmdResult {
+        self.respond(
+            http.expect("http should always be set when sending embedded"),
+            None,
+            Some(embed),
+            Some(components),
+            attachment,
         )
         .await
-        .map_err(CommandError::SerenityError)
     }
 }
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait DiscordAutocompleteInteraction {
+    /// Name of the command to autocomplete.
+    fn command_name(&self) -> String;
+
     fn get_user_input(&self) -> Option<String>;
 
     async fn create_autocomplete_response<F>(&self, http: Option<Arc<Http>>, f: F) -> CmdResult
@@ -117,6 +366,10 @@ pub trait DiscordAutocompleteInteraction {
 
 #[async_trait]
 impl DiscordAutocompleteInteraction for AutocompleteInteraction {
+    fn command_name(&self) -> String {
+        self.data.name.clone()
+    }
+
     fn get_user_input(&self) -> Option<String> {
         self.data
             .options
@@ -135,6 +388,72 @@ impl DiscordAutocompleteInteraction for AutocompleteInteraction {
     {
         self.create_autocomplete_response(http.expect("http always ok for autocompletion"), f)
             .await
-            .map_err(CommandError::SerenityError)
+            .map_err(|err| CommandError::SerenityError(Box::new(err)))
+    }
+}
+
+/// Wrapper for a `MessageComponentInteraction`, e.g. a button being clicked.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait DiscordComponentInteraction: Sync + Send {
+    /// The custom ID of the component which was used.
+    fn custom_id(&self) -> String;
+
+    /// The user who used the component.
+    fn user_id(&self) -> UserId;
+
+    /// Replace the embed and components of the message the component is on.
+    async fn update_message(
+        &self,
+        http: Option<Arc<Http>>,
+        embed: CreateEmbed,
+        components: CreateComponents,
+    ) -> CmdResult;
+
+    /// Reply with a message only the user who used the component can see.
+    async fn send_ephemeral_text(&self, http: Option<Arc<Http>>, text: &str) -> CmdResult;
+}
+
+#[async_trait]
+impl DiscordComponentInteraction for MessageComponentInteraction {
+    fn custom_id(&self) -> String {
+        self.data.custom_id.clone()
+    }
+
+    fn user_id(&self) -> UserId {
+        self.user.id
+    }
+
+    async fn update_message(
+        &self,
+        http: Option<Arc<Http>>,
+        embed: CreateEmbed,
+        components: CreateComponents,
+    ) -> CmdResult {
+        self.create_interaction_response(
+            http.expect("http should always be set when updating a message"),
+            |response| {
+                response
+                    .kind(InteractionResponseType::UpdateMessage)
+                    .interaction_response_data(|message| {
+                        message.set_embed(embed).set_components(components)
+                    })
+            },
+        )
+        .await
+        .map_err(|err| CommandError::SerenityError(Box::new(err)))
+    }
+
+    async fn send_ephemeral_text(&self, http: Option<Arc<Http>>, text: &str) -> CmdResult {
+        self.create_interaction_response(
+            http.expect("http should always be set when sending text"),
+            |response| {
+                response
+                    .kind(InteractionResponseType::ChannelMessageWithSource)
+                    .interaction_response_data(|message| message.content(text).ephemeral(true))
+            },
+        )
+        .await
+        .map_err(|err| CommandError::SerenityError(Box::new(err)))
     }
 }
@@ -1,27 +1,44 @@
-use std::collections::HashSet;
-
 use async_trait::async_trait;
 use serenity::{
     builder::{CreateApplicationCommand, CreateEmbed},
-    model::Timestamp,
+    model::prelude::command::CommandOptionType,
     utils::Color,
 };
-use siege_api::models::meta::Status;
+use siege_api::models::meta::{GameStatus, Platform, SIEGE_TITLE};
+use strum::IntoEnumIterator;
 
-use crate::SiegeApi;
+use crate::{constants::PLATFORM, formatting::FormatEmbedded};
 
 use super::{
-    context::DiscordContext, discord_app_command::DiscordAppCmd, CmdResult, CommandHandler,
+    context::DiscordContext, discord_app_command::DiscordAppCmd, siege_client, CmdResult,
+    CommandHandler,
 };
 
 pub struct GameStatusCommand;
 
 #[async_trait]
 impl CommandHandler for GameStatusCommand {
+    const NAME: &'static str = "status";
+
     fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
         command
-            .name("status")
+            .name(Self::NAME)
             .description("Get the current game status for Rainbow Six Siege")
+            .create_option(|option| {
+                option
+                    .name(PLATFORM)
+                    .description("Only show the status for this platform")
+                    .kind(CommandOptionType::String)
+                    .required(false);
+
+                Platform::iter()
+                    .filter(|x| *x != Platform::Unknown)
+                    .for_each(|x| {
+                        option.add_string_choice(x, x);
+                    });
+
+                option
+            })
     }
 
     async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
@@ -29,10 +46,14 @@ impl CommandHandler for GameStatusCommand {
         Ctx: DiscordContext + Send + Sync,
         Cmd: DiscordAppCmd + 'static + Send + Sync,
     {
+        let platform = command.extract_enum_option::<Platform>(PLATFORM);
+
         let game_status = {
-            let data = ctx.data().read().await;
-            let siege_client = data.get::<SiegeApi>().expect("client always registered");
-            match siege_client.siege_status().await {
+            let siege_client = siege_client(ctx, command).await?;
+            match siege_client
+                .siege_status(Some(SIEGE_TITLE.to_string()))
+                .await
+            {
                 Ok(id) => id,
                 Err(err) => {
                     tracing::error!("{err:?}");
@@ -43,54 +64,30 @@ impl CommandHandler for GameStatusCommand {
             }
         };
 
+        let game_status = game_status
+            .into_iter()
+            .filter(|x| platform.map_or(true, |platform| *x.platform() == platform))
+            .collect::<Vec<GameStatus>>();
+
+        if game_status.is_empty() {
+            return command
+                .send_text(
+                    ctx.http(),
+                    format!(
+                        "No status found for {}",
+                        platform.map_or("any platform".to_string(), |x| x.to_string())
+                    )
+                    .as_str(),
+                )
+                .await;
+        }
+
         command
             .send_embedded(
                 ctx.http().clone(),
                 CreateEmbed::default()
                     .title("Rainbow Six Siege status")
-                    .timestamp(Timestamp::now())
-                    .field(
-                        "Overall status",
-                        format!(
-                            "{}",
-                            game_status
-                                .iter()
-                                .find(|x| *x.status() != Status::Online)
-                                .map(|x| *x.status())
-                                .unwrap_or(Status::Online)
-                        ),
-                        false,
-                    )
-                    .field(
-                        "Details",
-                        game_status
-                            .iter()
-                            .map(|x| x.name())
-                            .fold(String::new(), |acc, next| acc + next + "\n"),
-                        true,
-                    )
-                    .field(
-                        "Status",
-                        game_status
-                            .iter()
-                            .map(|x| x.status().to_string())
-                            .fold(String::new(), |acc, next| acc + &next + "\n"),
-                        true,
-                    )
-                    .field(
-                        "Impacted features",
-                        Some(
-                            game_status
-                                .iter()
-                                .flat_map(|x| x.impacted_features())
-                                .collect::<HashSet<_>>()
-                                .iter()
-                                .fold(String::new(), |acc, next| acc + next + "\n"),
-                        )
-                        .filter(|s| !s.is_empty())
-                        .unwrap_or_else(|| "None".to_string()),
-                        false,
-                    )
+                    .format(&game_status)
                     .color(Color::DARK_RED)
                     .clone(),
             )
@@ -100,7 +97,8 @@ impl CommandHandler for GameStatusCommand {
 
 #[cfg(test)]
 mod test {
-    use siege_api::models::meta::GameStatus;
+    use mockall::predicate::*;
+    use serde_json::Value;
 
     use super::*;
 
@@ -125,28 +123,103 @@ mod test {
             .and_then(|x| x.as_str())
             .unwrap()
             .is_empty());
+
+        let options = command.0.get("options").unwrap().as_array().unwrap();
+        let opt = options.first().unwrap();
+        assert_eq!(opt.get("name").unwrap(), PLATFORM);
+        assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
+        assert_eq!(
+            opt.get("choices").unwrap().as_array().unwrap().len(),
+            Platform::iter().len() - 1
+        );
+    }
+
+    fn create_mock_client(filename: &'static str) -> MockSiegeClient {
+        let mut mock_client = MockSiegeClient::default();
+        mock_client
+            .expect_siege_status()
+            .withf(|title| title.as_deref() == Some(SIEGE_TITLE))
+            .once()
+            .returning(move |title| {
+                let content = std::fs::read_to_string(filename).unwrap();
+                let statuses: Vec<GameStatus> = serde_json::from_str(content.as_str()).unwrap();
+                Ok(statuses
+                    .into_iter()
+                    .filter(|x| x.name().starts_with(title.as_deref().unwrap()))
+                    .collect::<Vec<GameStatus>>())
+            });
+
+        mock_client
     }
 
     #[tokio::test]
     async fn validate_run() {
         let mut ctx = MockDiscordContext::new();
         ctx.expect_http().return_const(None);
+        register_client_in_type_map(&mut ctx, create_mock_client("../samples/game-status.json"))
+            .await;
 
-        let mut mock_client = MockSiegeClient::default();
-        mock_client.expect_siege_status().once().returning(|| {
-            let content = std::fs::read_to_string("../samples/game-status.json").unwrap();
-            let statuses: Vec<GameStatus> = serde_json::from_str(content.as_str()).unwrap();
-            Ok(statuses
-                .into_iter()
-                .filter(|x| x.name().starts_with("Rainbow Six Siege"))
-                .collect::<Vec<GameStatus>>())
-        });
-        register_client_in_type_map(&mut ctx, mock_client).await;
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_extract_enum_option::<Platform>()
+            .with(eq(PLATFORM))
+            .return_const(None);
+        command
+            .expect_send_embedded()
+            .once()
+            .returning(|_, _| Ok(()));
+
+        // Act
+        assert!(GameStatusCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_with_platform() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        register_client_in_type_map(
+            &mut ctx,
+            create_mock_client("../samples/game-status-with-error.json"),
+        )
+        .await;
 
         let mut command = MockDiscordAppCmd::new();
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_extract_enum_option::<Platform>()
+            .with(eq(PLATFORM))
+            .return_const(Some(Platform::PS4));
         command
             .expect_send_embedded()
             .once()
+            .withf(|_, embed| {
+                // Overall status, the single PS4 instance and impacted features.
+                embed.0.get("fields").unwrap().as_array().unwrap().len() == 3
+            })
+            .returning(|_, _| Ok(()));
+
+        // Act
+        assert!(GameStatusCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_platform_without_instances() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        register_client_in_type_map(&mut ctx, create_mock_client("../samples/game-status.json"))
+            .await;
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_extract_enum_option::<Platform>()
+            .with(eq(PLATFORM))
+            .return_const(Some(Platform::WII));
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("No status found for WII"))
             .returning(|_, _| Ok(()));
 
         // Act
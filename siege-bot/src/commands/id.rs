@@ -14,9 +14,11 @@ pub struct IdCommand;
 
 #[async_trait]
 impl CommandHandler for IdCommand {
+    const NAME: &'static str = "id";
+
     fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
         command
-            .name("id")
+            .name(Self::NAME)
             .description("Get a user id")
             .create_option(|option| {
                 option
@@ -69,7 +71,7 @@ mod test {
             .unwrap()
             .as_array()
             .unwrap()
-            .get(0)
+            .first()
             .unwrap()
             .as_object()
             .unwrap();
@@ -4,27 +4,33 @@ use serenity::{
     model::prelude::command::CommandOptionType,
     utils::Color,
 };
-use siege_api::maps::Map;
+use siege_api::{
+    maps::{get_map_details, Map},
+    models::SideOrAll,
+};
+use strum::IntoEnumIterator;
 
 use crate::{
-    constants::{AUTOCOMPLETE_LIMIT, GAME_MODE, NAME},
+    constants::{AUTOCOMPLETE_LIMIT, GAME_MODE, NAME, SIDE},
     formatting::FormatEmbedded,
-    SiegeApi,
 };
 
 use super::{
     context::DiscordContext,
+    defaults,
     discord_app_command::{DiscordAppCmd, DiscordAutocompleteInteraction},
-    AddUserOptionToCommand, AutocompleteHandler, CmdResult, CommandHandler,
+    siege_client, AddUserOptionToCommand, AutocompleteHandler, CmdResult, CommandHandler,
 };
 
 pub struct MapCommand;
 
 #[async_trait]
 impl CommandHandler for MapCommand {
+    const NAME: &'static str = "map";
+
     fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
         command
-            .name("map")
+            .name(Self::NAME)
             .description("Get detailed statistics about a specific map")
             .create_option(|option| {
                 option
@@ -36,6 +42,19 @@ impl CommandHandler for MapCommand {
             })
             .add_game_mode_option()
             .add_user_option()
+            .create_option(|option| {
+                option
+                    .name(SIDE)
+                    .description("Side to show statistics for. Defaults to both sides")
+                    .kind(CommandOptionType::String)
+                    .required(false);
+
+                SideOrAll::iter().for_each(|side| {
+                    option.add_string_choice(side, side);
+                });
+
+                option
+            })
     }
 
     async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
@@ -47,17 +66,20 @@ impl CommandHandler for MapCommand {
             .extract_enum_option(NAME)
             .expect("required argument");
 
-        let game_mode = command.extract_enum_option(GAME_MODE).unwrap_or_default();
+        let game_mode = command
+            .extract_enum_option(GAME_MODE)
+            .unwrap_or(defaults(ctx, command).await.game_mode);
+        let side = command.extract_enum_option(SIDE).unwrap_or(SideOrAll::All);
         let user = command.get_user_from_command_or_default();
         let player_id = ctx.lookup_siege_player(command, &user).await?;
 
-        tracing::info!("Getting statistics for map '{map:?}' for {}", user.name);
+        tracing::info!(
+            "Getting {side} statistics for map '{map:?}' for {}",
+            user.name
+        );
 
         let response = {
-            let data = ctx.data().read().await;
-            let siege_client = data
-                .get::<SiegeApi>()
-                .expect("Siege client is always registered");
+            let siege_client = siege_client(ctx, command).await?;
             match siege_client.get_maps(player_id).await {
                 Ok(data) => data,
                 Err(err) => {
@@ -67,7 +89,7 @@ impl CommandHandler for MapCommand {
             }
         };
 
-        let map = match response.get_map(map, game_mode) {
+        let map = match response.get_map_by_side(map, game_mode, side) {
             Some(map) => map,
             None => {
                 return command
@@ -80,17 +102,32 @@ impl CommandHandler for MapCommand {
             }
         };
 
-        command
-            .send_embedded(
-                ctx.http().clone(),
-                CreateEmbed::default()
-                    .thumbnail(map.name().image())
-                    .title(format!("Map statistics for {:?}", map.name()))
-                    .color(Color::GOLD)
-                    .format(map.statistics())
-                    .clone(),
-            )
-            .await
+        let mut embed = CreateEmbed::default();
+        if let Some(image) = map.name().image() {
+            embed.thumbnail(image);
+        }
+        embed
+            .title(match side {
+                SideOrAll::All => format!("Map statistics for {:?}", map.name()),
+                side => format!("{side} map statistics for {:?}", map.name()),
+            })
+            .color(Color::GOLD)
+            .format(get_map_details(*map.name()))
+            .format(map.statistics());
+
+        if side == SideOrAll::All {
+            let sides = [SideOrAll::Attacker, SideOrAll::Defender]
+                .into_iter()
+                .filter_map(|side| {
+                    response
+                        .get_map_by_side(*map.name(), game_mode, side)
+                        .map(|x| (side, x.statistics()))
+                })
+                .collect::<Vec<_>>();
+            embed.format(&sides);
+        }
+
+        command.send_embedded(ctx.http().clone(), embed).await
     }
 }
 
@@ -128,7 +165,7 @@ mod test {
     use mockall::predicate::*;
     use serde_json::Value;
     use serenity::model::user::User;
-    use siege_api::models::{AllOrRanked, StatisticResponse};
+    use siege_api::models::{GameModeOrAll, StatisticResponse};
     use uuid::Uuid;
 
     use crate::commands::{
@@ -172,7 +209,7 @@ mod test {
 
         let options = command.0.get("options").unwrap().as_array().unwrap();
         // Assert first options
-        let opt = options.get(0).unwrap();
+        let opt = options.first().unwrap();
         assert_eq!(opt.get("name").unwrap(), NAME);
         assert_eq!(*opt.get("required").unwrap(), Value::Bool(true));
         assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 3); // Corresponds to `CommandOptionType::User`
@@ -182,7 +219,7 @@ mod test {
         assert_eq!(opt.get("name").unwrap(), GAME_MODE);
         assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
         assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 3); // Corresponds to `CommandOptionType::String`
-        assert_eq!(opt.get("choices").unwrap().as_array().unwrap().len(), 2);
+        assert_eq!(opt.get("choices").unwrap().as_array().unwrap().len(), 4);
         assert!(!opt.get("description").unwrap().as_str().unwrap().is_empty());
 
         let opt = options.get(2).unwrap();
@@ -190,6 +227,11 @@ mod test {
         assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
         assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 6); // Corresponds to `CommandOptionType::User`
         assert!(!opt.get("description").unwrap().as_str().unwrap().is_empty());
+
+        let opt = options.get(3).unwrap();
+        assert_eq!(opt.get("name").unwrap(), SIDE);
+        assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
+        assert_eq!(opt.get("choices").unwrap().as_array().unwrap().len(), 3);
     }
 
     #[tokio::test]
@@ -214,6 +256,8 @@ mod test {
 
         // Setup command
         let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command.expect_defer().once().returning(|_| Ok(()));
         command
             .expect_get_user_from_command_or_default()
             .return_const(user.clone());
@@ -226,11 +270,86 @@ mod test {
             .expect_extract_enum_option()
             .once()
             .with(eq(GAME_MODE))
-            .return_const(AllOrRanked::All);
+            .return_const(GameModeOrAll::All);
+        command
+            .expect_extract_enum_option::<SideOrAll>()
+            .once()
+            .with(eq(SIDE))
+            .return_const(None);
         command
             .expect_send_embedded()
             .once()
-            .with(always(), always())
+            .withf(|_, embed| {
+                embed
+                    .0
+                    .get("fields")
+                    .unwrap()
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .any(|x| x.get("name").unwrap() == "Attack vs defense")
+            })
+            .return_once(|_, _| Ok(()));
+
+        assert!(MapCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_with_side() {
+        let user = User::default();
+        let siege_id = Uuid::new_v4();
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        ctx.expect_lookup_siege_player::<MockDiscordAppCmd>()
+            .with(always(), eq(user.clone()))
+            .once()
+            .returning(move |_, _| Ok(siege_id));
+
+        let mut mock_client = MockSiegeClient::default();
+        mock_client.expect_get_maps().once().returning(|_| {
+            let content = std::fs::read_to_string("../samples/maps.json").unwrap();
+            let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
+            Ok(stats)
+        });
+        register_client_in_type_map(&mut ctx, mock_client).await;
+
+        // Setup command
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_get_user_from_command_or_default()
+            .return_const(user.clone());
+        command
+            .expect_extract_enum_option()
+            .once()
+            .with(eq(NAME))
+            .return_const(Map::Oregon);
+        command
+            .expect_extract_enum_option::<GameModeOrAll>()
+            .once()
+            .with(eq(GAME_MODE))
+            .return_const(None);
+        command
+            .expect_extract_enum_option()
+            .once()
+            .with(eq(SIDE))
+            .return_const(SideOrAll::Defender);
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(|_, embed| {
+                embed.0.get("title").unwrap() == "Defender map statistics for Oregon"
+                    && !embed
+                        .0
+                        .get("fields")
+                        .unwrap()
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .any(|x| x.get("name").unwrap() == "Attack vs defense")
+            })
             .return_once(|_, _| Ok(()));
 
         assert!(MapCommand::run(&ctx, &command).await.is_ok());
@@ -257,6 +376,8 @@ mod test {
 
         // Setup command
         let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command.expect_defer().once().returning(|_| Ok(()));
         command
             .expect_get_user_from_command_or_default()
             .return_const(user.clone());
@@ -267,10 +388,15 @@ mod test {
             .return_const(Map::Bank);
 
         command
-            .expect_extract_enum_option::<AllOrRanked>()
+            .expect_extract_enum_option::<GameModeOrAll>()
             .once()
             .with(eq(GAME_MODE))
             .return_const(None);
+        command
+            .expect_extract_enum_option::<SideOrAll>()
+            .once()
+            .with(eq(SIDE))
+            .return_const(None);
         command
             .expect_send_text()
             .once()
@@ -0,0 +1,329 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serenity::{
+    builder::{CreateApplicationCommand, CreateEmbed},
+    model::prelude::{
+        command::CommandOptionType, interaction::application_command::CommandDataOptionValue,
+    },
+    utils::Color,
+};
+use siege_api::models::SideOrAll;
+
+use crate::{
+    constants::{GAME_MODE, MINIMUM_ROUNDS},
+    formatting::FormatEmbedded,
+    map_bans::{aggregate, recommend},
+};
+
+use super::{
+    context::DiscordContext, defaults, discord_app_command::DiscordAppCmd, join_names, lookup_team,
+    siege_client, AddUserOptionToCommand, CmdResult, CommandHandler,
+};
+
+/// Maps with fewer rounds than this across the team are ignored by default.
+const DEFAULT_MINIMUM_ROUNDS: u64 = 20;
+
+/// Number of maps to recommend banning and picking.
+const RECOMMENDATIONS: usize = 3;
+
+pub struct MapBansCommand;
+
+#[async_trait]
+impl CommandHandler for MapBansCommand {
+    const NAME: &'static str = "map_bans";
+    const COOLDOWN: Option<Duration> = Some(Duration::from_secs(30));
+
+    fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        command
+            .name(Self::NAME)
+            .description("Recommend which ranked maps a team should ban and pick")
+            .add_team_options()
+            .add_game_mode_option()
+            .create_option(|option| {
+                option
+                    .name(MINIMUM_ROUNDS)
+                    .description(format!(
+                        "Ignore maps the team has played for less than this limit. Defaults to {DEFAULT_MINIMUM_ROUNDS}"
+                    ))
+                    .kind(CommandOptionType::Integer)
+                    .min_int_value(0)
+                    .required(false)
+            })
+    }
+
+    async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
+    where
+        Ctx: DiscordContext + Send + Sync,
+        Cmd: DiscordAppCmd + 'static + Send + Sync,
+    {
+        let game_mode = command
+            .extract_enum_option(GAME_MODE)
+            .unwrap_or(defaults(ctx, command).await.game_mode);
+        let minimum_rounds = match command.get_option(MINIMUM_ROUNDS) {
+            Some(CommandDataOptionValue::Integer(value)) => value.max(0) as u64,
+            _ => DEFAULT_MINIMUM_ROUNDS,
+        };
+
+        let (players, missing) = lookup_team(ctx, command).await;
+        if players.is_empty() {
+            return command
+                .send_text(
+                    ctx.http(),
+                    "None of the users have linked a Siege player.\nUse the `/add` command to link a Discord profile to an Ubisoft name",
+                )
+                .await;
+        }
+
+        tracing::info!(
+            "Recommending map bans for {} players with at least {minimum_rounds} rounds",
+            players.len()
+        );
+
+        let responses = {
+            let siege_client = siege_client(ctx, command).await?;
+
+            let mut responses = Vec::with_capacity(players.len());
+            for (user, player_id) in &players {
+                match siege_client.get_maps(*player_id).await {
+                    Ok(data) => responses.push(data),
+                    Err(err) => {
+                        tracing::error!("Failed to fetch data for {}: {err:?}", user.name)
+                    }
+                }
+            }
+            responses
+        };
+
+        if responses.is_empty() {
+            return command.send_text(ctx.http(), "Failed to fetch data").await;
+        }
+
+        let maps = aggregate(
+            responses
+                .iter()
+                .flat_map(|x| x.get_maps(game_mode, SideOrAll::All)),
+        );
+        let recommendations = recommend(maps, minimum_rounds, RECOMMENDATIONS);
+        if recommendations.picks.is_empty() && recommendations.bans.is_empty() {
+            return command
+                .send_text(
+                    ctx.http(),
+                    format!("The team has not played {minimum_rounds} rounds on any ranked map")
+                        .as_str(),
+                )
+                .await;
+        }
+
+        let mut embed = CreateEmbed::default();
+        embed
+            .title(format!(
+                "Map bans for {}",
+                join_names(players.iter().map(|(user, _)| user))
+            ))
+            .color(Color::DARK_RED)
+            .format(&recommendations);
+        if !missing.is_empty() {
+            embed.footer(|footer| {
+                footer.text(format!(
+                    "No Siege player linked for {}",
+                    join_names(&missing)
+                ))
+            });
+        }
+
+        command.send_embedded(ctx.http().clone(), embed).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use mockall::predicate::*;
+    use serde_json::Value;
+    use serenity::{
+        model::{prelude::UserId, user::User},
+        prelude::{RwLock, TypeMap},
+    };
+    use siege_api::models::{GameModeOrAll, StatisticResponse};
+    use uuid::Uuid;
+
+    use crate::{
+        commands::{
+            context::MockDiscordContext, discord_app_command::MockDiscordAppCmd,
+            test::MockSiegeClient,
+        },
+        config::Defaults,
+        constants::PLAYERS,
+        siege_player_lookup::{MockPlayerLookup, SiegePlayerLookup},
+        SiegeApi,
+    };
+
+    use super::*;
+
+    static PLAYER_ID: Uuid = Uuid::from_u128(1);
+
+    fn load_maps() -> StatisticResponse {
+        let content = std::fs::read_to_string("../samples/maps.json").unwrap();
+        serde_json::from_str(content.as_str()).unwrap()
+    }
+
+    #[test]
+    fn validate_register() {
+        let mut command = CreateApplicationCommand::default();
+        let command = MapBansCommand::register(&mut command);
+
+        // Assert
+        assert_eq!(command.0.get("name").unwrap(), "map_bans");
+        assert!(!command
+            .0
+            .get("description")
+            .and_then(|x| x.as_str())
+            .unwrap()
+            .is_empty());
+
+        let options = command.0.get("options").unwrap().as_array().unwrap();
+        assert_eq!(options.len(), PLAYERS.len() + 2);
+        PLAYERS.iter().zip(options).for_each(|(name, opt)| {
+            assert_eq!(opt.get("name").unwrap(), name);
+            assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 6); // Corresponds to `CommandOptionType::User`
+            assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
+        });
+
+        let opt = options.get(5).unwrap();
+        assert_eq!(opt.get("name").unwrap(), GAME_MODE);
+
+        let opt = options.get(6).unwrap();
+        assert_eq!(opt.get("name").unwrap(), MINIMUM_ROUNDS);
+        assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 4); // Corresponds to `CommandOptionType::Integer`
+    }
+
+    async fn create_context(
+        lookup: MockPlayerLookup,
+        client: MockSiegeClient,
+    ) -> MockDiscordContext {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let data = Arc::new(RwLock::new(TypeMap::default()));
+        {
+            let mut data = data.write().await;
+            data.insert::<SiegeApi>(Arc::new(client));
+            data.insert::<SiegePlayerLookup>(Arc::new(RwLock::new(lookup)));
+            data.insert::<Defaults>(Defaults::default());
+        }
+        ctx.expect_data().return_const(data);
+
+        ctx
+    }
+
+    fn create_command(users: &[User]) -> MockDiscordAppCmd {
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command
+            .expect_extract_enum_option::<GameModeOrAll>()
+            .with(eq(GAME_MODE))
+            .return_const(None);
+        command
+            .expect_get_option()
+            .with(eq(MINIMUM_ROUNDS))
+            .return_const(None);
+        PLAYERS.iter().enumerate().for_each(|(i, name)| {
+            command.expect_get_option().with(eq(*name)).return_const(
+                users
+                    .get(i)
+                    .map(|user| CommandDataOptionValue::User(user.clone(), None)),
+            );
+        });
+
+        command
+    }
+
+    fn create_user(id: u64, name: &str) -> User {
+        let mut user = User::default();
+        user.id = UserId(id);
+        user.name = name.to_string();
+        user
+    }
+
+    #[tokio::test]
+    async fn validate_run() {
+        let users = [
+            create_user(1, "Linked"),
+            create_user(2, "Unlinked"),
+            create_user(1, "Linked"),
+        ];
+
+        let mut lookup = MockPlayerLookup::default();
+        lookup
+            .expect_get()
+            .returning(|id| (*id == UserId(1)).then_some(&PLAYER_ID));
+
+        let mut client = MockSiegeClient::default();
+        client
+            .expect_get_maps()
+            .with(eq(PLAYER_ID))
+            .once()
+            .returning(|_| Ok(load_maps()));
+
+        let ctx = create_context(lookup, client).await;
+        let mut command = create_command(&users);
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(|_, embed| {
+                let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+                embed.0.get("title").unwrap() == "Map bans for Linked"
+                    && fields.len() == 2
+                    && embed.0.get("footer").unwrap().get("text").unwrap()
+                        == "No Siege player linked for Unlinked"
+            })
+            .returning(|_, _| Ok(()));
+
+        assert!(MapBansCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_without_linked_players() {
+        let mut lookup = MockPlayerLookup::default();
+        lookup.expect_get().returning(|_| None);
+
+        let ctx = create_context(lookup, MockSiegeClient::default()).await;
+        let mut command = create_command(&[]);
+        command
+            .expect_get_user_from_command_or_default()
+            .return_const(create_user(1, "Sender"));
+        command
+            .expect_send_text()
+            .once()
+            .withf(|_, text| text.starts_with("None of the users have linked a Siege player"))
+            .returning(|_, _| Ok(()));
+
+        assert!(MapBansCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_api_failed() {
+        let mut lookup = MockPlayerLookup::default();
+        lookup.expect_get().returning(|_| Some(&PLAYER_ID));
+
+        let mut client = MockSiegeClient::default();
+        client
+            .expect_get_maps()
+            .once()
+            .returning(|_| Err(siege_api::auth::ConnectError::InvalidPassword));
+
+        let ctx = create_context(lookup, client).await;
+        let mut command = create_command(&[create_user(1, "Linked")]);
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("Failed to fetch data"))
+            .returning(|_, _| Ok(()));
+
+        assert!(MapBansCommand::run(&ctx, &command).await.is_ok());
+    }
+}
@@ -0,0 +1,227 @@
+use async_trait::async_trait;
+use serenity::{
+    builder::CreateApplicationCommand,
+    model::{
+        prelude::{
+            command::CommandOptionType, interaction::application_command::CommandDataOptionValue,
+        },
+        Permissions,
+    },
+};
+
+use crate::{constants::ENABLED, notification_channels::NotificationChannels};
+
+use super::{
+    context::DiscordContext, discord_app_command::DiscordAppCmd, CmdResult, CommandHandler,
+};
+
+pub struct NotificationsCommand;
+
+#[async_trait]
+impl CommandHandler for NotificationsCommand {
+    const NAME: &'static str = "notifications";
+    const PERMISSIONS: Option<Permissions> = Some(Permissions::MANAGE_GUILD);
+
+    fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        command
+            .name(Self::NAME)
+            .description(
+                "Post a message in this channel when the status of Siege's servers changes",
+            )
+            .dm_permission(false)
+            .create_option(|option| {
+                option
+                    .name(ENABLED)
+                    .description("Enable or disable notifications. Defaults to enabled")
+                    .kind(CommandOptionType::Boolean)
+                    .required(false)
+            })
+    }
+
+    async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
+    where
+        Ctx: DiscordContext + Send + Sync,
+        Cmd: DiscordAppCmd + 'static + Send + Sync,
+    {
+        let guild_id = match command.guild_id() {
+            Some(guild_id) => guild_id,
+            None => {
+                return command
+                    .send_text(ctx.http(), "Notifications can only be set up in a server")
+                    .await
+            }
+        };
+        let enabled = match command.get_option(ENABLED) {
+            Some(CommandDataOptionValue::Boolean(value)) => value,
+            _ => true,
+        };
+        let channel_id = command.channel_id();
+        tracing::info!("Setting notifications for {guild_id} in {channel_id} to {enabled}");
+
+        let result = {
+            let data = ctx.data().read().await;
+            let lookup = data
+                .get::<NotificationChannels>()
+                .expect("always registered");
+            let mut lookup = lookup.write().await;
+
+            if enabled {
+                lookup.insert(&guild_id, channel_id)
+            } else {
+                lookup.remove(&guild_id)
+            }
+        };
+
+        match result {
+            Ok(_) if enabled => {
+                command
+                    .send_text(
+                        ctx.http(),
+                        "Status notifications will be posted in this channel",
+                    )
+                    .await
+            }
+            Ok(_) => {
+                command
+                    .send_text(ctx.http(), "Status notifications disabled")
+                    .await
+            }
+            Err(err) => {
+                tracing::error!("Failed to store notification channel: {err:?}");
+                command
+                    .send_text(ctx.http(), "Failed to update notifications")
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use mockall::predicate::*;
+    use serenity::{
+        model::prelude::{ChannelId, GuildId},
+        prelude::{RwLock, TypeMap},
+    };
+
+    use crate::{
+        commands::{context::MockDiscordContext, discord_app_command::MockDiscordAppCmd},
+        notification_channels::MockChannelLookup,
+    };
+
+    use super::*;
+
+    #[test]
+    fn validate_register() {
+        let mut command = CreateApplicationCommand::default();
+
+        // Act
+        let command = NotificationsCommand::register(&mut command);
+
+        // Assert
+        assert_eq!(command.0.get("name").unwrap(), "notifications");
+        assert!(!command
+            .0
+            .get("description")
+            .and_then(|x| x.as_str())
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            NotificationsCommand::PERMISSIONS,
+            Some(Permissions::MANAGE_GUILD)
+        );
+    }
+
+    async fn register_lookup(ctx: &mut MockDiscordContext, lookup: MockChannelLookup) {
+        let data = Arc::new(RwLock::new(TypeMap::default()));
+        {
+            let mut data = data.write().await;
+            data.insert::<NotificationChannels>(Arc::new(RwLock::new(lookup)));
+        }
+        ctx.expect_data().return_const(data);
+    }
+
+    #[tokio::test]
+    async fn validate_run_enable() {
+        let guild_id = GuildId::from(1);
+        let channel_id = ChannelId::from(2);
+
+        let mut lookup = MockChannelLookup::new();
+        lookup
+            .expect_insert()
+            .with(eq(guild_id), eq(channel_id))
+            .once()
+            .returning(|_, _| Ok(()));
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        register_lookup(&mut ctx, lookup).await;
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(Some(guild_id));
+        command.expect_channel_id().return_const(channel_id);
+        command
+            .expect_get_option()
+            .with(eq(ENABLED))
+            .return_const(None);
+        command
+            .expect_send_text()
+            .once()
+            .with(
+                always(),
+                eq("Status notifications will be posted in this channel"),
+            )
+            .returning(|_, _| Ok(()));
+
+        assert!(NotificationsCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_disable() {
+        let guild_id = GuildId::from(1);
+
+        let mut lookup = MockChannelLookup::new();
+        lookup
+            .expect_remove()
+            .with(eq(guild_id))
+            .once()
+            .returning(|_| Ok(()));
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        register_lookup(&mut ctx, lookup).await;
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(Some(guild_id));
+        command.expect_channel_id().return_const(ChannelId::from(2));
+        command
+            .expect_get_option()
+            .with(eq(ENABLED))
+            .return_const(Some(CommandDataOptionValue::Boolean(false)));
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("Status notifications disabled"))
+            .returning(|_, _| Ok(()));
+
+        assert!(NotificationsCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_outside_guild() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("Notifications can only be set up in a server"))
+            .returning(|_, _| Ok(()));
+
+        assert!(NotificationsCommand::run(&ctx, &command).await.is_ok());
+    }
+}
@@ -4,27 +4,33 @@ use serenity::{
     model::prelude::command::CommandOptionType,
     utils::Color,
 };
-use siege_api::operator::Operator;
+use siege_api::{
+    models::SideOrAll,
+    operator::{get_operator_details, Operator},
+};
 
 use crate::{
     constants::{AUTOCOMPLETE_LIMIT, GAME_MODE, NAME},
     formatting::FormatEmbedded,
-    SiegeApi,
+    rendering::profile_chart,
 };
 
 use super::{
     context::DiscordContext,
+    defaults,
     discord_app_command::{DiscordAppCmd, DiscordAutocompleteInteraction},
-    AddUserOptionToCommand, AutocompleteHandler, CmdResult, CommandHandler,
+    siege_client, AddUserOptionToCommand, AutocompleteHandler, CmdResult, CommandHandler,
 };
 
 pub struct OperatorCommand;
 
 #[async_trait]
 impl CommandHandler for OperatorCommand {
+    const NAME: &'static str = "operator";
+
     fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
         command
-            .name("operator")
+            .name(Self::NAME)
             .description("Get detailed statistics about an operator")
             .create_option(|option| {
                 option
@@ -46,7 +52,9 @@ impl CommandHandler for OperatorCommand {
         let operator = command
             .extract_enum_option(NAME)
             .expect("required argument");
-        let game_mode = command.extract_enum_option(GAME_MODE).unwrap_or_default();
+        let game_mode = command
+            .extract_enum_option(GAME_MODE)
+            .unwrap_or(defaults(ctx, command).await.game_mode);
         let user = command.get_user_from_command_or_default();
         let player_id = ctx.lookup_siege_player(command, &user).await?;
 
@@ -56,10 +64,7 @@ impl CommandHandler for OperatorCommand {
         );
 
         let response = {
-            let data = ctx.data().read().await;
-            let siege_client = data
-                .get::<SiegeApi>()
-                .expect("Siege client is always registered");
+            let siege_client = siege_client(ctx, command).await?;
             match siege_client.get_operators(player_id).await {
                 Ok(data) => data,
                 Err(err) => {
@@ -69,6 +74,11 @@ impl CommandHandler for OperatorCommand {
             }
         };
 
+        // Compare with both sides if the side of the operator is unknown
+        let side =
+            SideOrAll::try_from(*get_operator_details(operator).side()).unwrap_or(SideOrAll::All);
+        let totals = response.get_side_totals(game_mode, side);
+
         let operator = match response.get_operator(operator, game_mode) {
             Some(operator) => operator,
             None => {
@@ -83,17 +93,23 @@ impl CommandHandler for OperatorCommand {
             }
         };
 
-        command
-            .send_embedded(
-                ctx.http().clone(),
-                CreateEmbed::default()
-                    .thumbnail(operator.name().avatar_url())
-                    .title(format!("Operator statistics for {}", operator.name()))
-                    .color(Color::BLUE)
-                    .format(operator.statistics())
-                    .clone(),
-            )
-            .await
+        let mut embed = CreateEmbed::default();
+        embed
+            .thumbnail(operator.name().avatar_url())
+            .title(format!("Operator statistics for {}", operator.name()))
+            .color(Color::BLUE)
+            .format(operator.statistics())
+            .format(&(operator, totals));
+
+        match profile_chart(operator.statistics()) {
+            Some(chart) => {
+                embed.attachment(&chart.filename);
+                command
+                    .send_embedded_with_attachment(ctx.http(), embed, chart)
+                    .await
+            }
+            None => command.send_embedded(ctx.http(), embed).await,
+        }
     }
 }
 
@@ -131,7 +147,7 @@ mod test {
     use mockall::predicate::*;
     use serde_json::Value;
     use serenity::model::user::User;
-    use siege_api::models::{AllOrRanked, StatisticResponse};
+    use siege_api::models::{GameModeOrAll, StatisticResponse};
     use uuid::Uuid;
 
     use crate::{
@@ -179,7 +195,7 @@ mod test {
 
         let options = command.0.get("options").unwrap().as_array().unwrap();
         // Assert first options
-        let opt = options.get(0).unwrap();
+        let opt = options.first().unwrap();
         assert_eq!(opt.get("name").unwrap(), NAME);
         assert_eq!(*opt.get("required").unwrap(), Value::Bool(true));
         assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 3);
@@ -189,7 +205,7 @@ mod test {
         assert_eq!(opt.get("name").unwrap(), GAME_MODE);
         assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
         assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 3); // Corresponds to `CommandOptionType::String`
-        assert_eq!(opt.get("choices").unwrap().as_array().unwrap().len(), 2);
+        assert_eq!(opt.get("choices").unwrap().as_array().unwrap().len(), 4);
         assert!(!opt.get("description").unwrap().as_str().unwrap().is_empty());
 
         let opt = options.get(2).unwrap();
@@ -221,6 +237,8 @@ mod test {
 
         // Setup command
         let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command.expect_defer().once().returning(|_| Ok(()));
         command
             .expect_get_user_from_command_or_default()
             .return_const(user.clone());
@@ -233,12 +251,24 @@ mod test {
             .expect_extract_enum_option()
             .once()
             .with(eq(GAME_MODE))
-            .return_const(AllOrRanked::All);
+            .return_const(GameModeOrAll::All);
         command
-            .expect_send_embedded()
+            .expect_send_embedded_with_attachment()
             .once()
-            .with(always(), always())
-            .return_once(|_, _| Ok(()));
+            .withf(|_, embed, attachment| {
+                attachment.filename == "profile.png"
+                    && embed.0.get("image").unwrap().get("url").unwrap()
+                        == "attachment://profile.png"
+                    && embed
+                        .0
+                        .get("fields")
+                        .unwrap()
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .any(|x| x.get("name").unwrap() == "Compared to all Attacker rounds")
+            })
+            .return_once(|_, _, _| Ok(()));
 
         assert!(OperatorCommand::run(&ctx, &command).await.is_ok());
     }
@@ -264,6 +294,8 @@ mod test {
 
         // Setup command
         let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command.expect_defer().once().returning(|_| Ok(()));
         command
             .expect_get_user_from_command_or_default()
             .return_const(user.clone());
@@ -273,7 +305,7 @@ mod test {
             .with(eq(NAME))
             .return_const(Operator::Ying);
         command
-            .expect_extract_enum_option::<AllOrRanked>()
+            .expect_extract_enum_option::<GameModeOrAll>()
             .once()
             .with(eq(GAME_MODE))
             .return_const(None);
@@ -0,0 +1,274 @@
+use async_trait::async_trait;
+use serenity::{
+    builder::{CreateApplicationCommand, CreateEmbed},
+    model::prelude::{
+        command::CommandOptionType, interaction::application_command::CommandDataOptionValue,
+    },
+    utils::Color,
+};
+use siege_api::operator::{get_loadout, get_operator_details, Operator};
+
+use crate::{
+    constants::{GAME_MODE, NAME, STATISTICS},
+    formatting::FormatEmbedded,
+};
+
+use super::{
+    context::DiscordContext,
+    defaults,
+    discord_app_command::{DiscordAppCmd, DiscordAutocompleteInteraction},
+    operator::OperatorCommand,
+    siege_client, AddUserOptionToCommand, AutocompleteHandler, CmdResult, CommandHandler,
+};
+
+pub struct OperatorInfoCommand;
+
+#[async_trait]
+impl CommandHandler for OperatorInfoCommand {
+    const NAME: &'static str = "operator_info";
+
+    fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        command
+            .name(Self::NAME)
+            .description("Get the biography and kit of an operator")
+            .create_option(|option| {
+                option
+                    .name(NAME)
+                    .description("Name of the operator")
+                    .kind(CommandOptionType::String)
+                    .set_autocomplete(true)
+                    .required(true)
+            })
+            .create_option(|option| {
+                option
+                    .name(STATISTICS)
+                    .description("Include the statistics of the user for this operator")
+                    .kind(CommandOptionType::Boolean)
+                    .required(false)
+            })
+            .add_game_mode_option()
+            .add_user_option()
+    }
+
+    async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
+    where
+        Ctx: DiscordContext + Send + Sync,
+        Cmd: DiscordAppCmd + 'static + Send + Sync,
+    {
+        let operator: Operator = command
+            .extract_enum_option(NAME)
+            .expect("required argument");
+        let include_statistics = matches!(
+            command.get_option(STATISTICS),
+            Some(CommandDataOptionValue::Boolean(true))
+        );
+
+        let mut embed = CreateEmbed::default();
+        embed
+            .thumbnail(operator.avatar_url())
+            .title(format!("Operator profile for {operator}"))
+            .color(Color::BLUE)
+            .format(get_operator_details(operator))
+            .format(get_loadout(operator));
+
+        if include_statistics {
+            let game_mode = command
+                .extract_enum_option(GAME_MODE)
+                .unwrap_or(defaults(ctx, command).await.game_mode);
+            let user = command.get_user_from_command_or_default();
+            let player_id = ctx.lookup_siege_player(command, &user).await?;
+
+            tracing::info!(
+                "Getting profile and statistics for operator '{operator}' for {}",
+                user.name
+            );
+
+            let response = {
+                let siege_client = siege_client(ctx, command).await?;
+                match siege_client.get_operators(player_id).await {
+                    Ok(data) => data,
+                    Err(err) => {
+                        tracing::error!("Failed to fetch data: {err:?}");
+                        return command.send_text(ctx.http(), "Failed to fetch data").await;
+                    }
+                }
+            };
+
+            match response.get_operator(operator, game_mode) {
+                Some(statistics) => {
+                    embed
+                        .title(format!("Operator profile and statistics for {operator}"))
+                        .format(statistics.statistics());
+                }
+                None => {
+                    embed.footer(|footer| {
+                        footer.text(format!(
+                            "{user} has not played as {operator}",
+                            user = user.tag()
+                        ))
+                    });
+                }
+            }
+        }
+
+        command.send_embedded(ctx.http().clone(), embed).await
+    }
+}
+
+#[async_trait]
+impl AutocompleteHandler for OperatorInfoCommand {
+    /// Handle auto complete for operator names.
+    async fn handle_autocomplete<Ctx, Cmd>(ctx: &Ctx, cmd: &Cmd) -> CmdResult
+    where
+        Ctx: DiscordContext + Send + Sync,
+        Cmd: DiscordAutocompleteInteraction + Send + Sync,
+    {
+        OperatorCommand::handle_autocomplete(ctx, cmd).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::*;
+    use serde_json::Value;
+    use serenity::model::user::User;
+    use siege_api::models::{GameModeOrAll, StatisticResponse};
+    use uuid::Uuid;
+
+    use crate::{
+        commands::{
+            context::MockDiscordContext,
+            discord_app_command::{MockDiscordAppCmd, MockDiscordAutocompleteInteraction},
+            test::{register_client_in_type_map, MockSiegeClient},
+        },
+        constants::USER,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn validate_autocomplete() {
+        let mut ctx = MockDiscordContext::default();
+        ctx.expect_http().return_const(None);
+
+        let mut cmd = MockDiscordAutocompleteInteraction::default();
+        cmd.expect_get_user_input()
+            .return_const(Some("Br".to_string()));
+        cmd.expect_create_autocomplete_response()
+            .once()
+            .return_once(|_, _| Ok(()));
+
+        assert!(OperatorInfoCommand::handle_autocomplete(&ctx, &cmd)
+            .await
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_register() {
+        let mut command = CreateApplicationCommand::default();
+        let command = OperatorInfoCommand::register(&mut command);
+
+        // Assert
+        assert_eq!(command.0.get("name").unwrap(), "operator_info");
+        assert!(!command
+            .0
+            .get("description")
+            .and_then(|x| x.as_str())
+            .unwrap()
+            .is_empty());
+
+        let options = command.0.get("options").unwrap().as_array().unwrap();
+        let opt = options.first().unwrap();
+        assert_eq!(opt.get("name").unwrap(), NAME);
+        assert_eq!(*opt.get("required").unwrap(), Value::Bool(true));
+        assert_eq!(*opt.get("autocomplete").unwrap(), Value::Bool(true));
+
+        let opt = options.get(1).unwrap();
+        assert_eq!(opt.get("name").unwrap(), STATISTICS);
+        assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
+        assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 5); // Corresponds to `CommandOptionType::Boolean`
+
+        assert_eq!(options.get(2).unwrap().get("name").unwrap(), GAME_MODE);
+        assert_eq!(options.get(3).unwrap().get("name").unwrap(), USER);
+    }
+
+    #[tokio::test]
+    async fn validate_run() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command
+            .expect_extract_enum_option()
+            .once()
+            .with(eq(NAME))
+            .return_const(Operator::Brava);
+        command
+            .expect_get_option()
+            .with(eq(STATISTICS))
+            .return_const(None);
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(|_, embed| {
+                embed.0.get("title").unwrap() == "Operator profile for Brava"
+                    && embed.0.get("fields").unwrap().as_array().unwrap().len() == 14
+            })
+            .return_once(|_, _| Ok(()));
+
+        assert!(OperatorInfoCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_with_statistics() {
+        let user = User::default();
+        let siege_id = Uuid::new_v4();
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        ctx.expect_lookup_siege_player::<MockDiscordAppCmd>()
+            .with(always(), eq(user.clone()))
+            .once()
+            .returning(move |_, _| Ok(siege_id));
+
+        let mut mock_client = MockSiegeClient::default();
+        mock_client.expect_get_operators().once().returning(|_| {
+            let content = std::fs::read_to_string("../samples/operators.json").unwrap();
+            let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
+            Ok(stats)
+        });
+        register_client_in_type_map(&mut ctx, mock_client).await;
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_get_user_from_command_or_default()
+            .return_const(user.clone());
+        command
+            .expect_extract_enum_option()
+            .once()
+            .with(eq(NAME))
+            .return_const(Operator::Ying);
+        command
+            .expect_get_option()
+            .with(eq(STATISTICS))
+            .return_const(Some(CommandDataOptionValue::Boolean(true)));
+        command
+            .expect_extract_enum_option()
+            .once()
+            .with(eq(GAME_MODE))
+            .return_const(GameModeOrAll::All);
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(|_, embed| {
+                embed.0.get("title").unwrap() == "Operator profile and statistics for Ying"
+                    && embed.0.get("fields").unwrap().as_array().unwrap().len() > 14
+            })
+            .return_once(|_, _| Ok(()));
+
+        assert!(OperatorInfoCommand::run(&ctx, &command).await.is_ok());
+    }
+}
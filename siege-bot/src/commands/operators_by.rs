@@ -0,0 +1,368 @@
+use async_trait::async_trait;
+use serenity::{
+    builder::{CreateApplicationCommand, CreateEmbed},
+    model::prelude::{
+        command::CommandOptionType, interaction::application_command::CommandDataOptionValue,
+    },
+    utils::Color,
+};
+use siege_api::{
+    data::operator::{catalogue, OperatorFilter},
+    game_models::{Role, Season, Side, Speed},
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+    constants::{COUNTRY, MATCH_ALL_ROLES, ROLE, SEASON, SECOND_ROLE, SIDE, SPEED, UNIT},
+    formatting::FormatEmbedded,
+};
+
+use super::{
+    context::DiscordContext, discord_app_command::DiscordAppCmd, CmdResult, CommandHandler,
+};
+
+pub struct OperatorsByCommand;
+
+#[async_trait]
+impl CommandHandler for OperatorsByCommand {
+    const NAME: &'static str = "operators_by";
+
+    fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        command
+            .name(Self::NAME)
+            .description("List operators matching a side, roles, speed, unit, country or season")
+            .create_option(|option| {
+                option
+                    .name(SIDE)
+                    .description("Only list operators on this side")
+                    .kind(CommandOptionType::String)
+                    .add_string_choice(Side::Attacker, Side::Attacker)
+                    .add_string_choice(Side::Defender, Side::Defender)
+                    .required(false)
+            })
+            .create_option(|option| {
+                option
+                    .name(ROLE)
+                    .description("Only list operators with this role")
+                    .kind(CommandOptionType::String)
+                    .required(false);
+
+                Role::iter()
+                    .filter(|x| *x != Role::Unknown)
+                    .for_each(|role| {
+                        option.add_string_choice(role, role);
+                    });
+
+                option
+            })
+            .create_option(|option| {
+                option
+                    .name(SECOND_ROLE)
+                    .description("Another role to list operators for")
+                    .kind(CommandOptionType::String)
+                    .required(false);
+
+                Role::iter()
+                    .filter(|x| *x != Role::Unknown)
+                    .for_each(|role| {
+                        option.add_string_choice(role, role);
+                    });
+
+                option
+            })
+            .create_option(|option| {
+                option
+                    .name(MATCH_ALL_ROLES)
+                    .description("Require operators to have all the roles instead of any of them")
+                    .kind(CommandOptionType::Boolean)
+                    .required(false)
+            })
+            .create_option(|option| {
+                option
+                    .name(SPEED)
+                    .description("Only list operators with this speed")
+                    .kind(CommandOptionType::String)
+                    .required(false);
+
+                Speed::iter().for_each(|speed| {
+                    option.add_string_choice(speed, speed);
+                });
+
+                option
+            })
+            .create_option(|option| {
+                option
+                    .name(UNIT)
+                    .description("Only list operators from this unit, e.g. GIGN")
+                    .kind(CommandOptionType::String)
+                    .required(false)
+            })
+            .create_option(|option| {
+                option
+                    .name(COUNTRY)
+                    .description("Only list operators from this country code, e.g. FR")
+                    .kind(CommandOptionType::String)
+                    .required(false)
+            })
+            .create_option(|option| {
+                option
+                    .name(SEASON)
+                    .description("Only list operators introduced in this season, e.g. Y8S1")
+                    .kind(CommandOptionType::String)
+                    .required(false)
+            })
+    }
+
+    async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
+    where
+        Ctx: DiscordContext + Send + Sync,
+        Cmd: DiscordAppCmd + 'static + Send + Sync,
+    {
+        let match_all_roles = matches!(
+            command.get_option(MATCH_ALL_ROLES),
+            Some(CommandDataOptionValue::Boolean(true))
+        );
+        let get_string = |name| match command.get_option(name) {
+            Some(CommandDataOptionValue::String(value)) => Some(value),
+            _ => None,
+        };
+
+        let mut filter = OperatorFilter::default();
+        if let Some(side) = command.extract_enum_option::<Side>(SIDE) {
+            filter = filter.side(side);
+        }
+        for role in [ROLE, SECOND_ROLE]
+            .into_iter()
+            .filter_map(|name| command.extract_enum_option::<Role>(name))
+        {
+            filter = if match_all_roles {
+                filter.all_roles(role)
+            } else {
+                filter.any_role(role)
+            };
+        }
+        if let Some(speed) = command.extract_enum_option::<Speed>(SPEED) {
+            filter = filter.speed(speed);
+        }
+        if let Some(unit) = get_string(UNIT) {
+            filter = filter.unit(unit);
+        }
+        if let Some(country) = get_string(COUNTRY) {
+            filter = filter.country_code(country);
+        }
+        if let Some(season) = get_string(SEASON) {
+            match season.parse::<Season>() {
+                Ok(season) => filter = filter.season_introduced(season),
+                Err(_) => {
+                    return command
+                        .send_text(
+                            ctx.http(),
+                            format!("'{season}' is not a valid season, e.g. Y8S1").as_str(),
+                        )
+                        .await
+                }
+            }
+        }
+
+        tracing::info!("Listing operators matching {filter:?}");
+
+        let operators = catalogue().filter(&filter).collect::<Vec<_>>();
+        if operators.is_empty() {
+            return command
+                .send_text(ctx.http(), "No operators match the given filters")
+                .await;
+        }
+
+        command
+            .send_embedded(
+                ctx.http().clone(),
+                CreateEmbed::default()
+                    .title("Operators")
+                    .color(Color::BLUE)
+                    .format(&operators)
+                    .clone(),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::*;
+    use serde_json::Value;
+
+    use crate::commands::{context::MockDiscordContext, discord_app_command::MockDiscordAppCmd};
+
+    use super::*;
+
+    #[test]
+    fn validate_register() {
+        let mut command = CreateApplicationCommand::default();
+        let command = OperatorsByCommand::register(&mut command);
+
+        // Assert
+        assert_eq!(command.0.get("name").unwrap(), "operators_by");
+        assert!(!command
+            .0
+            .get("description")
+            .and_then(|x| x.as_str())
+            .unwrap()
+            .is_empty());
+
+        let options = command.0.get("options").unwrap().as_array().unwrap();
+        assert_eq!(
+            options
+                .iter()
+                .map(|x| x.get("name").unwrap().as_str().unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                SIDE,
+                ROLE,
+                SECOND_ROLE,
+                MATCH_ALL_ROLES,
+                SPEED,
+                UNIT,
+                COUNTRY,
+                SEASON
+            ]
+        );
+        options.iter().for_each(|opt| {
+            assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
+        });
+        assert_eq!(
+            options[1].get("choices").unwrap().as_array().unwrap().len(),
+            Role::iter().count() - 1
+        );
+        assert_eq!(
+            options[4].get("choices").unwrap().as_array().unwrap().len(),
+            3
+        );
+    }
+
+    fn create_command(
+        side: Option<Side>,
+        roles: (Option<Role>, Option<Role>),
+        match_all_roles: bool,
+        season: Option<&str>,
+    ) -> MockDiscordAppCmd {
+        let mut command = MockDiscordAppCmd::new();
+        command
+            .expect_get_option()
+            .with(eq(MATCH_ALL_ROLES))
+            .return_const(Some(CommandDataOptionValue::Boolean(match_all_roles)));
+        command
+            .expect_extract_enum_option::<Side>()
+            .with(eq(SIDE))
+            .return_const(side);
+        command
+            .expect_extract_enum_option::<Role>()
+            .with(eq(ROLE))
+            .return_const(roles.0);
+        command
+            .expect_extract_enum_option::<Role>()
+            .with(eq(SECOND_ROLE))
+            .return_const(roles.1);
+        command
+            .expect_extract_enum_option::<Speed>()
+            .with(eq(SPEED))
+            .return_const(None);
+        command
+            .expect_get_option()
+            .with(eq(UNIT))
+            .return_const(None);
+        command
+            .expect_get_option()
+            .with(eq(COUNTRY))
+            .return_const(None);
+        command
+            .expect_get_option()
+            .with(eq(SEASON))
+            .return_const(season.map(|x| CommandDataOptionValue::String(x.to_string())));
+
+        command
+    }
+
+    #[tokio::test]
+    async fn validate_run() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut command = create_command(
+            Some(Side::Defender),
+            (Some(Role::Anchor), Some(Role::Secure)),
+            true,
+            None,
+        );
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(|_, embed| {
+                let description = embed.0.get("description").unwrap().as_str().unwrap();
+                !description.is_empty()
+                    && description
+                        .lines()
+                        .all(|line| line.contains("Anchor") && line.contains("Secure"))
+            })
+            .returning(|_, _| Ok(()));
+
+        assert!(OperatorsByCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_with_season() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut command = create_command(None, (None, None), false, Some("y8s3"));
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(|_, embed| {
+                embed
+                    .0
+                    .get("description")
+                    .unwrap()
+                    .as_str()
+                    .unwrap()
+                    .starts_with("**Ram**")
+            })
+            .returning(|_, _| Ok(()));
+
+        assert!(OperatorsByCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_invalid_season() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut command = create_command(None, (None, None), false, Some("Y42"));
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("'Y42' is not a valid season, e.g. Y8S1"))
+            .returning(|_, _| Ok(()));
+
+        assert!(OperatorsByCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_without_matches() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut command = create_command(
+            Some(Side::Attacker),
+            (None, None),
+            false,
+            Some("Y8S2"), // Fenrir is the only operator from Y8S2
+        );
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("No operators match the given filters"))
+            .returning(|_, _| Ok(()));
+
+        assert!(OperatorsByCommand::run(&ctx, &command).await.is_ok());
+    }
+}
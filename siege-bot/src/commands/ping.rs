@@ -9,9 +9,11 @@ pub struct PingCommand;
 
 #[async_trait]
 impl CommandHandler for PingCommand {
+    const NAME: &'static str = "ping";
+
     fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
         command
-            .name("ping")
+            .name(Self::NAME)
             .description("A ping command to verify that the bot is alive")
     }
 
@@ -0,0 +1,418 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use serenity::{
+    builder::{CreateApplicationCommand, CreateApplicationCommands},
+    model::{
+        prelude::{interaction::autocomplete::AutocompleteInteraction, UserId},
+        Permissions,
+    },
+    prelude::Context,
+};
+
+use crate::config::ConfigError;
+
+use super::{
+    context::DiscordContext,
+    discord_app_command::{CommandInteraction, DiscordAppCmd, DiscordAutocompleteInteraction},
+    AutocompleteHandler, CmdResult, CommandError, CommandHandler,
+};
+
+type BoxFuture<'a> = Pin<Box<dyn Future<Output = CmdResult> + Send + 'a>>;
+type HandlerFn<Ctx, Interaction> = for<'a> fn(&'a Ctx, &'a Interaction) -> BoxFuture<'a>;
+
+fn run_command<'a, T, Ctx, Cmd>(ctx: &'a Ctx, command: &'a Cmd) -> BoxFuture<'a>
+where
+    T: CommandHandler,
+    Ctx: DiscordContext + Send + Sync + 'static,
+    Cmd: DiscordAppCmd + Send + Sync + 'static,
+{
+    T::run(ctx, command)
+}
+
+fn run_autocomplete<'a, T, Ctx, Auto>(ctx: &'a Ctx, autocomplete: &'a Auto) -> BoxFuture<'a>
+where
+    T: AutocompleteHandler,
+    Ctx: DiscordContext + Send + Sync + 'static,
+    Auto: DiscordAutocompleteInteraction + Send + Sync + 'static,
+{
+    T::handle_autocomplete(ctx, autocomplete)
+}
+
+/// Everything declared by a `CommandHandler`, with the generic handlers
+/// turned into function pointers.
+struct RegisteredCommand<Ctx, Cmd, Auto> {
+    name: &'static str,
+    register: fn(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
+    run: HandlerFn<Ctx, Cmd>,
+    autocomplete: Option<HandlerFn<Ctx, Auto>>,
+    permissions: Option<Permissions>,
+    cooldown: Option<Duration>,
+}
+
+/// All commands the bot handles. Registration of the commands with Discord,
+/// dispatching of interactions and autocompletion all go through here, so a
+/// command only has to be added once.
+pub struct CommandRegistry<Ctx = Context, Cmd = CommandInteraction, Auto = AutocompleteInteraction>
+{
+    commands: Vec<RegisteredCommand<Ctx, Cmd, Auto>>,
+    /// When each user last used a command with a cooldown.
+    last_used: Mutex<HashMap<(UserId, &'static str), Instant>>,
+}
+
+impl<Ctx, Cmd, Auto> Default for CommandRegistry<Ctx, Cmd, Auto> {
+    fn default() -> Self {
+        Self {
+            commands: Vec::new(),
+            last_used: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<Ctx, Cmd, Auto> CommandRegistry<Ctx, Cmd, Auto>
+where
+    Ctx: DiscordContext + Send + Sync + 'static,
+    Cmd: DiscordAppCmd + Send + Sync + 'static,
+    Auto: DiscordAutocompleteInteraction + Send + Sync + 'static,
+{
+    /// Add a command without autocompletion.
+    pub fn command<T: CommandHandler>(self) -> Self {
+        self.add::<T>(None)
+    }
+
+    /// Add a command which handles autocompletion of its options.
+    pub fn command_with_autocomplete<T: CommandHandler + AutocompleteHandler>(self) -> Self {
+        self.add::<T>(Some(run_autocomplete::<T, Ctx, Auto>))
+    }
+
+    /// Only keep the commands with the given names, failing if any of them is
+    /// not a command.
+    pub fn only(mut self, names: &[String]) -> Result<Self, ConfigError> {
+        if let Some(unknown) = names.iter().find(|name| self.find(name).is_none()) {
+            return Err(ConfigError::UnknownCommand(unknown.clone()));
+        }
+
+        self.commands
+            .retain(|registered| names.iter().any(|name| name == registered.name));
+        Ok(self)
+    }
+
+    fn add<T: CommandHandler>(mut self, autocomplete: Option<HandlerFn<Ctx, Auto>>) -> Self {
+        assert!(
+            self.find(T::NAME).is_none(),
+            "command `{}` is registered twice",
+            T::NAME
+        );
+
+        self.commands.push(RegisteredCommand {
+            name: T::NAME,
+            register: T::register,
+            run: run_command::<T, Ctx, Cmd>,
+            autocomplete,
+            permissions: T::PERMISSIONS,
+            cooldown: T::COOLDOWN,
+        });
+        self
+    }
+
+    /// Create all commands, including their permissions.
+    pub fn register<'a>(
+        &self,
+        commands: &'a mut CreateApplicationCommands,
+    ) -> &'a mut CreateApplicationCommands {
+        self.commands.iter().for_each(|registered| {
+            commands.create_application_command(|command| {
+                let command = (registered.register)(command);
+                match registered.permissions {
+                    Some(permissions) => command.default_member_permissions(permissions),
+                    None => command,
+                }
+            });
+        });
+
+        commands
+    }
+
+    /// Run the invoked command, unless the user has to wait for its cooldown.
+    /// The cooldown starts before the command runs, so the same user cannot
+    /// run it twice at once, and is refunded if the command fails.
+    pub async fn run(&self, ctx: &Ctx, command: &Cmd) -> CmdResult {
+        let registered = self
+            .find(command.command_name().as_str())
+            .ok_or(CommandError::CommandNotFound)?;
+
+        if let Some(remaining) = self.start_cooldown(registered, command.user_id()) {
+            return command
+                .send_text(
+                    ctx.http(),
+                    format!(
+                        "Please wait {} seconds before using `/{}` again",
+                        remaining.as_secs_f64().ceil(),
+                        registered.name
+                    )
+                    .as_str(),
+                )
+                .await;
+        }
+
+        let result = (registered.run)(ctx, command).await;
+        if result.is_err() {
+            self.refund_cooldown(registered, command.user_id());
+        }
+        result
+    }
+
+    /// Handle autocompletion for the command.
+    pub async fn autocomplete(&self, ctx: &Ctx, autocomplete: &Auto) -> CmdResult {
+        let name = autocomplete.command_name();
+        let registered = self
+            .find(name.as_str())
+            .ok_or(CommandError::CommandNotFound)?;
+
+        match registered.autocomplete {
+            Some(handler) => handler(ctx, autocomplete).await,
+            None => Err(CommandError::AutocompleteNotSupported),
+        }
+    }
+
+    fn find(&self, name: &str) -> Option<&RegisteredCommand<Ctx, Cmd, Auto>> {
+        self.commands.iter().find(|x| x.name == name)
+    }
+
+    /// Start the cooldown of the command for the user. Returns the remaining
+    /// time if the user is still on cooldown from an earlier use.
+    fn start_cooldown(
+        &self,
+        registered: &RegisteredCommand<Ctx, Cmd, Auto>,
+        user_id: UserId,
+    ) -> Option<Duration> {
+        let cooldown = registered.cooldown?;
+        let mut last_used = self.last_used.lock().expect("lock should not be poisoned");
+        let now = Instant::now();
+
+        if let Some(used) = last_used.get(&(user_id, registered.name)) {
+            let elapsed = now.duration_since(*used);
+            if elapsed < cooldown {
+                return Some(cooldown - elapsed);
+            }
+        }
+
+        last_used.insert((user_id, registered.name), now);
+        None
+    }
+
+    /// Let the user run the command again right away, e.g. after it failed.
+    fn refund_cooldown(&self, registered: &RegisteredCommand<Ctx, Cmd, Auto>, user_id: UserId) {
+        self.last_used
+            .lock()
+            .expect("lock should not be poisoned")
+            .remove(&(user_id, registered.name));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::*;
+
+    use crate::commands::{
+        context::MockDiscordContext,
+        discord_app_command::{MockDiscordAppCmd, MockDiscordAutocompleteInteraction},
+        map::MapCommand,
+        map_bans::MapBansCommand,
+        notifications::NotificationsCommand,
+        ping::PingCommand,
+    };
+
+    use super::*;
+
+    type TestRegistry =
+        CommandRegistry<MockDiscordContext, MockDiscordAppCmd, MockDiscordAutocompleteInteraction>;
+
+    fn create_registry() -> TestRegistry {
+        TestRegistry::default()
+            .command::<PingCommand>()
+            .command::<NotificationsCommand>()
+            .command::<MapBansCommand>()
+            .command_with_autocomplete::<MapCommand>()
+    }
+
+    fn create_command(name: &str) -> MockDiscordAppCmd {
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_command_name().return_const(name.to_string());
+        command.expect_user_id().return_const(UserId(1));
+        command
+    }
+
+    #[test]
+    fn register_all_commands() {
+        let registry = create_registry();
+        let mut commands = CreateApplicationCommands::default();
+        registry.register(&mut commands);
+
+        let names = commands
+            .0
+            .iter()
+            .map(|x| x.get("name").unwrap().as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["ping", "notifications", "map_bans", "map"]);
+
+        let permissions = commands
+            .0
+            .iter()
+            .map(|x| x.get("default_member_permissions"))
+            .collect::<Vec<_>>();
+        assert_eq!(permissions[0], None);
+        assert_eq!(
+            permissions[1].unwrap(),
+            &Permissions::MANAGE_GUILD.bits().to_string()
+        );
+    }
+
+    #[test]
+    fn only_enabled_commands() {
+        let registry = create_registry()
+            .only(&["map".to_string(), "ping".to_string()])
+            .unwrap();
+        let mut commands = CreateApplicationCommands::default();
+        registry.register(&mut commands);
+
+        let names = commands
+            .0
+            .iter()
+            .map(|x| x.get("name").unwrap().as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["ping", "map"]);
+    }
+
+    #[test]
+    fn enable_unknown_command() {
+        assert!(matches!(
+            create_registry().only(&["ping".to_string(), "pong".to_string()]),
+            Err(ConfigError::UnknownCommand(name)) if name == "pong"
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "registered twice")]
+    fn register_command_twice() {
+        TestRegistry::default()
+            .command::<PingCommand>()
+            .command::<PingCommand>();
+    }
+
+    #[tokio::test]
+    async fn dispatch_command() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut command = create_command("ping");
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("Hey, I'm alive!"))
+            .returning(|_, _| Ok(()));
+
+        assert!(create_registry().run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn dispatch_unknown_command() {
+        let ctx = MockDiscordContext::new();
+        let command = create_command("unknown");
+
+        assert!(matches!(
+            create_registry().run(&ctx, &command).await,
+            Err(CommandError::CommandNotFound)
+        ));
+    }
+
+    #[test]
+    fn cooldown_per_user_and_command() {
+        let registry = create_registry();
+        let map_bans = registry.find("map_bans").unwrap();
+        let ping = registry.find("ping").unwrap();
+
+        assert_eq!(registry.start_cooldown(map_bans, UserId(1)), None);
+        assert!(registry.start_cooldown(map_bans, UserId(1)).unwrap() <= Duration::from_secs(30));
+        assert_eq!(registry.start_cooldown(map_bans, UserId(2)), None);
+        assert_eq!(registry.start_cooldown(ping, UserId(1)), None);
+        assert_eq!(registry.start_cooldown(ping, UserId(1)), None);
+    }
+
+    #[test]
+    fn refund_cooldown() {
+        let registry = create_registry();
+        let map_bans = registry.find("map_bans").unwrap();
+
+        assert_eq!(registry.start_cooldown(map_bans, UserId(1)), None);
+        assert_eq!(registry.start_cooldown(map_bans, UserId(2)), None);
+        registry.refund_cooldown(map_bans, UserId(1));
+
+        assert_eq!(registry.start_cooldown(map_bans, UserId(1)), None);
+        assert!(registry.start_cooldown(map_bans, UserId(2)).is_some());
+    }
+
+    #[tokio::test]
+    async fn reply_while_on_cooldown() {
+        let registry = create_registry();
+        assert_eq!(
+            registry.start_cooldown(registry.find("map_bans").unwrap(), UserId(1)),
+            None
+        );
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut command = create_command("map_bans");
+        command
+            .expect_send_text()
+            .once()
+            .withf(|_, text| text == "Please wait 30 seconds before using `/map_bans` again")
+            .returning(|_, _| Ok(()));
+
+        assert!(registry.run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn dispatch_autocomplete() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut autocomplete = MockDiscordAutocompleteInteraction::default();
+        autocomplete
+            .expect_command_name()
+            .return_const("map".to_string());
+        autocomplete
+            .expect_get_user_input()
+            .return_const(Some("Ba".to_string()));
+        autocomplete
+            .expect_create_autocomplete_response()
+            .once()
+            .return_once(|_, _| Ok(()));
+
+        assert!(create_registry()
+            .autocomplete(&ctx, &autocomplete)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn autocomplete_not_supported() {
+        let ctx = MockDiscordContext::new();
+
+        let mut autocomplete = MockDiscordAutocompleteInteraction::default();
+        autocomplete
+            .expect_command_name()
+            .return_const("ping".to_string());
+
+        assert!(matches!(
+            create_registry().autocomplete(&ctx, &autocomplete).await,
+            Err(CommandError::AutocompleteNotSupported)
+        ));
+    }
+}
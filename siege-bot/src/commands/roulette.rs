@@ -0,0 +1,389 @@
+use async_trait::async_trait;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serenity::{
+    builder::{CreateApplicationCommand, CreateEmbed},
+    model::prelude::{
+        command::CommandOptionType, interaction::application_command::CommandDataOptionValue,
+    },
+    utils::Color,
+};
+use siege_api::{
+    data::operator::{catalogue, OperatorFilter},
+    game_models::{Role, Side},
+    models::{GameModeOrAll, SideOrAll},
+    operator::{get_operator_details, Operator},
+};
+use thiserror::Error;
+
+use crate::{
+    constants::{PLAYED_ONLY, ROLE_COVERAGE, SIDE, TEAM_SIZE},
+    formatting::FormatEmbedded,
+};
+
+use super::{
+    context::DiscordContext, discord_app_command::DiscordAppCmd, siege_client,
+    AddUserOptionToCommand, CmdResult, CommandHandler,
+};
+
+#[derive(Debug, Error, PartialEq, Eq)]
+enum RouletteError {
+    #[error("Not enough operators to pick {0} different operators from")]
+    NotEnoughOperators(usize),
+    #[error("No operator with the role {0} to pick from")]
+    MissingRole(Role),
+}
+
+/// The role which a team on the given side should always include.
+fn required_role(side: Side) -> Role {
+    match side {
+        Side::Defender => Role::Anchor,
+        _ => Role::HardBreach,
+    }
+}
+
+/// Pick `size` different operators at random from the pool. If a role is
+/// required, at least one of the picked operators will have that role.
+fn pick_team<R: Rng>(
+    rng: &mut R,
+    pool: &[Operator],
+    required_role: Option<Role>,
+    size: usize,
+) -> Result<Vec<Operator>, RouletteError> {
+    if pool.len() < size {
+        return Err(RouletteError::NotEnoughOperators(size));
+    }
+
+    let mut team = Vec::with_capacity(size);
+    if let Some(role) = required_role {
+        let candidates = pool
+            .iter()
+            .filter(|op| get_operator_details(**op).roles().contains(&role))
+            .collect::<Vec<_>>();
+        let pick = candidates
+            .choose(rng)
+            .ok_or(RouletteError::MissingRole(role))?;
+        team.push(**pick);
+    }
+
+    let remaining = pool
+        .iter()
+        .filter(|op| !team.contains(op))
+        .copied()
+        .collect::<Vec<_>>();
+    team.extend(remaining.choose_multiple(rng, size - team.len()));
+    team.shuffle(rng);
+
+    Ok(team)
+}
+
+pub struct RouletteCommand;
+
+#[async_trait]
+impl CommandHandler for RouletteCommand {
+    const NAME: &'static str = "roulette";
+
+    fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        command
+            .name(Self::NAME)
+            .description("Pick random operators for a full team")
+            .create_option(|option| {
+                option
+                    .name(SIDE)
+                    .description("Side to pick operators for")
+                    .kind(CommandOptionType::String)
+                    .add_string_choice(Side::Attacker, Side::Attacker)
+                    .add_string_choice(Side::Defender, Side::Defender)
+                    .required(true)
+            })
+            .create_option(|option| {
+                option
+                    .name(PLAYED_ONLY)
+                    .description("Only pick operators the user has played before. Defaults to false")
+                    .kind(CommandOptionType::Boolean)
+                    .required(false)
+            })
+            .create_option(|option| {
+                option
+                    .name(ROLE_COVERAGE)
+                    .description(
+                        "Include a hard breacher on attack or an anchor on defense. Defaults to true",
+                    )
+                    .kind(CommandOptionType::Boolean)
+                    .required(false)
+            })
+            .add_user_option()
+    }
+
+    async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
+    where
+        Ctx: DiscordContext + Send + Sync,
+        Cmd: DiscordAppCmd + 'static + Send + Sync,
+    {
+        let side = command
+            .extract_enum_option::<Side>(SIDE)
+            .expect("required argument");
+        let get_bool = |name, default| match command.get_option(name) {
+            Some(CommandDataOptionValue::Boolean(value)) => value,
+            _ => default,
+        };
+        let played_only = get_bool(PLAYED_ONLY, false);
+        let role_coverage = get_bool(ROLE_COVERAGE, true);
+
+        let mut pool = catalogue()
+            .filter(&OperatorFilter::default().side(side))
+            .map(|x| *x.name())
+            .filter(|op| !matches!(op, Operator::Recruit | Operator::NoClass))
+            .collect::<Vec<_>>();
+
+        if played_only {
+            let Ok(side_filter) = SideOrAll::try_from(side) else {
+                return command
+                    .send_text(ctx.http(), &format!("Unknown side {side}"))
+                    .await;
+            };
+            let user = command.get_user_from_command_or_default();
+            let player_id = ctx.lookup_siege_player(command, &user).await?;
+
+            let response = {
+                let siege_client = siege_client(ctx, command).await?;
+                match siege_client.get_operators(player_id).await {
+                    Ok(data) => data,
+                    Err(err) => {
+                        tracing::error!("Failed to fetch data: {err:?}");
+                        return command.send_text(ctx.http(), "Failed to fetch data").await;
+                    }
+                }
+            };
+
+            let played = response
+                .get_operators(GameModeOrAll::All, side_filter)
+                .iter()
+                .map(|x| *x.name())
+                .collect::<Vec<_>>();
+            pool.retain(|op| played.contains(op));
+        }
+
+        let required_role = role_coverage.then(|| required_role(side));
+        tracing::info!(
+            "Picking {TEAM_SIZE} {side} operators from {} with required role {required_role:?}",
+            pool.len()
+        );
+
+        let team = match pick_team(&mut StdRng::from_entropy(), &pool, required_role, TEAM_SIZE) {
+            Ok(team) => team,
+            Err(err) => return command.send_text(ctx.http(), &err.to_string()).await,
+        };
+        let team = team
+            .into_iter()
+            .map(get_operator_details)
+            .collect::<Vec<_>>();
+
+        command
+            .send_embedded(
+                ctx.http().clone(),
+                CreateEmbed::default()
+                    .title(format!("Roulette for {side}"))
+                    .color(Color::DARK_GOLD)
+                    .format(&team)
+                    .clone(),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::*;
+    use serde_json::Value;
+    use serenity::model::user::User;
+    use siege_api::models::StatisticResponse;
+    use uuid::Uuid;
+
+    use crate::{
+        commands::{
+            context::MockDiscordContext,
+            discord_app_command::MockDiscordAppCmd,
+            test::{register_client_in_type_map, MockSiegeClient},
+        },
+        constants::USER,
+    };
+
+    use super::*;
+
+    fn attackers() -> Vec<Operator> {
+        catalogue()
+            .filter(&OperatorFilter::default().side(Side::Attacker))
+            .map(|x| *x.name())
+            .collect()
+    }
+
+    #[test]
+    fn pick_team_without_duplicates() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        (0..100).for_each(|_| {
+            let mut team = pick_team(&mut rng, &attackers(), None, TEAM_SIZE).unwrap();
+            assert_eq!(team.len(), TEAM_SIZE);
+
+            team.sort_by_key(|x| x.to_string());
+            team.dedup();
+            assert_eq!(team.len(), TEAM_SIZE);
+        });
+    }
+
+    #[test]
+    fn pick_team_with_role_coverage() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        (0..100).for_each(|_| {
+            let team =
+                pick_team(&mut rng, &attackers(), Some(Role::HardBreach), TEAM_SIZE).unwrap();
+            assert!(team.iter().any(|op| get_operator_details(*op)
+                .roles()
+                .contains(&Role::HardBreach)));
+        });
+    }
+
+    #[test]
+    fn pick_team_with_small_pool() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let pool = vec![Operator::Ash, Operator::Sledge];
+
+        assert_eq!(
+            pick_team(&mut rng, &pool, None, TEAM_SIZE),
+            Err(RouletteError::NotEnoughOperators(TEAM_SIZE))
+        );
+        assert_eq!(
+            pick_team(&mut rng, &pool, Some(Role::HardBreach), 2),
+            Err(RouletteError::MissingRole(Role::HardBreach))
+        );
+    }
+
+    #[test]
+    fn validate_required_role() {
+        assert_eq!(required_role(Side::Attacker), Role::HardBreach);
+        assert_eq!(required_role(Side::Defender), Role::Anchor);
+    }
+
+    #[test]
+    fn validate_register() {
+        let mut command = CreateApplicationCommand::default();
+        let command = RouletteCommand::register(&mut command);
+
+        // Assert
+        assert_eq!(command.0.get("name").unwrap(), "roulette");
+        assert!(!command
+            .0
+            .get("description")
+            .and_then(|x| x.as_str())
+            .unwrap()
+            .is_empty());
+
+        let options = command.0.get("options").unwrap().as_array().unwrap();
+        let opt = options.first().unwrap();
+        assert_eq!(opt.get("name").unwrap(), SIDE);
+        assert_eq!(*opt.get("required").unwrap(), Value::Bool(true));
+
+        let opt = options.get(1).unwrap();
+        assert_eq!(opt.get("name").unwrap(), PLAYED_ONLY);
+        assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 5); // Corresponds to `CommandOptionType::Boolean`
+
+        let opt = options.get(2).unwrap();
+        assert_eq!(opt.get("name").unwrap(), ROLE_COVERAGE);
+        assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 5);
+
+        assert_eq!(options.get(3).unwrap().get("name").unwrap(), USER);
+    }
+
+    #[tokio::test]
+    async fn validate_run() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut command = MockDiscordAppCmd::new();
+        command
+            .expect_extract_enum_option()
+            .with(eq(SIDE))
+            .return_const(Side::Defender);
+        command
+            .expect_get_option()
+            .with(eq(PLAYED_ONLY))
+            .return_const(None);
+        command
+            .expect_get_option()
+            .with(eq(ROLE_COVERAGE))
+            .return_const(None);
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(|_, embed| {
+                let description = embed.0.get("description").unwrap().as_str().unwrap();
+                description.lines().count() == TEAM_SIZE && description.contains("Anchor")
+            })
+            .returning(|_, _| Ok(()));
+
+        assert!(RouletteCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_played_only() {
+        let user = User::default();
+        let siege_id = Uuid::new_v4();
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        ctx.expect_lookup_siege_player::<MockDiscordAppCmd>()
+            .with(always(), eq(user.clone()))
+            .once()
+            .returning(move |_, _| Ok(siege_id));
+
+        let content = std::fs::read_to_string("../samples/operators.json").unwrap();
+        let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
+        let played = stats
+            .get_operators(GameModeOrAll::All, SideOrAll::Attacker)
+            .iter()
+            .map(|x| x.name().to_string())
+            .collect::<Vec<_>>();
+
+        let mut mock_client = MockSiegeClient::default();
+        mock_client.expect_get_operators().once().returning(|_| {
+            let content = std::fs::read_to_string("../samples/operators.json").unwrap();
+            let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
+            Ok(stats)
+        });
+        register_client_in_type_map(&mut ctx, mock_client).await;
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_get_user_from_command_or_default()
+            .return_const(user.clone());
+        command
+            .expect_extract_enum_option()
+            .with(eq(SIDE))
+            .return_const(Side::Attacker);
+        command
+            .expect_get_option()
+            .with(eq(PLAYED_ONLY))
+            .return_const(Some(CommandDataOptionValue::Boolean(true)));
+        command
+            .expect_get_option()
+            .with(eq(ROLE_COVERAGE))
+            .return_const(Some(CommandDataOptionValue::Boolean(false)));
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(move |_, embed| {
+                let description = embed.0.get("description").unwrap().as_str().unwrap();
+                description.lines().count() == TEAM_SIZE
+                    && description.lines().all(|line| {
+                        played
+                            .iter()
+                            .any(|op| line.starts_with(&format!("**{op}**")))
+                    })
+            })
+            .returning(|_, _| Ok(()));
+
+        assert!(RouletteCommand::run(&ctx, &command).await.is_ok());
+    }
+}
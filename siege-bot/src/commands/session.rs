@@ -0,0 +1,443 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serenity::{
+    builder::{CreateApplicationCommand, CreateEmbed},
+    model::prelude::command::CommandOptionType,
+};
+use siege_api::{
+    client::SiegeClient,
+    models::{PlatformFamily, RankedV2Response, StatisticResponse},
+};
+use uuid::Uuid;
+
+use crate::{
+    constants::{END, START},
+    formatting::FormatEmbedded,
+    play_session::{PlaySessions, SessionSnapshot},
+};
+
+use super::{
+    context::DiscordContext, defaults, discord_app_command::DiscordAppCmd, siege_client, CmdResult,
+    CommandError, CommandHandler,
+};
+
+pub struct SessionCommand;
+
+#[async_trait]
+impl CommandHandler for SessionCommand {
+    const NAME: &'static str = "session";
+
+    fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        command
+            .name(Self::NAME)
+            .description("Track what you play in a session")
+            .create_option(|option| {
+                option
+                    .name(START)
+                    .description("Start a session, saving your current statistics")
+                    .kind(CommandOptionType::SubCommand)
+            })
+            .create_option(|option| {
+                option
+                    .name(END)
+                    .description("End your session and show what you played in it")
+                    .kind(CommandOptionType::SubCommand)
+            })
+    }
+
+    async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
+    where
+        Ctx: DiscordContext + Send + Sync,
+        Cmd: DiscordAppCmd + 'static + Send + Sync,
+    {
+        match command.subcommand().as_deref() {
+            Some(START) => start(ctx, command).await,
+            Some(END) => end(ctx, command).await,
+            _ => Err(CommandError::CommandNotFound),
+        }
+    }
+}
+
+/// Take a snapshot of the statistics of the player.
+async fn snapshot(
+    siege_client: &dyn SiegeClient,
+    player_id: Uuid,
+    platform: PlatformFamily,
+) -> Result<SessionSnapshot, siege_api::auth::ConnectError> {
+    let profiles: RankedV2Response = siege_client.get_full_profiles(player_id).await?;
+    let operators: StatisticResponse = siege_client.get_operators(player_id).await?;
+
+    Ok(SessionSnapshot::new(
+        Utc::now(),
+        player_id,
+        platform,
+        &profiles,
+        &operators,
+    ))
+}
+
+async fn start<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
+where
+    Ctx: DiscordContext + Send + Sync,
+    Cmd: DiscordAppCmd + 'static + Send + Sync,
+{
+    let user = command.get_user_from_command_or_default();
+    let sessions = ctx
+        .data()
+        .read()
+        .await
+        .get::<PlaySessions>()
+        .expect("always registered")
+        .clone();
+
+    let running = sessions.read().await.get(&user.id);
+    if let Some(running) = running {
+        return command
+            .send_text(
+                ctx.http(),
+                format!(
+                    "Your session started <t:{}:R>. End it with `/session end` first",
+                    running.taken.timestamp()
+                )
+                .as_str(),
+            )
+            .await;
+    }
+
+    let player_id = ctx.lookup_siege_player(command, &user).await?;
+    let platform = defaults(ctx, command).await.platform;
+    tracing::info!("Starting a session for {} on {platform}", user.name);
+
+    let snapshot = {
+        let siege_client = siege_client(ctx, command).await?;
+        match snapshot(siege_client.as_ref(), player_id, platform).await {
+            Ok(snapshot) => snapshot,
+            Err(err) => {
+                tracing::error!("Failed to fetch data: {err:?}");
+                return command.send_text(ctx.http(), "Failed to fetch data").await;
+            }
+        }
+    };
+
+    if let Err(err) = sessions.write().await.insert(&user.id, snapshot) {
+        tracing::error!("Failed to store the session: {err:?}");
+        return command
+            .send_text(ctx.http(), "Failed to start the session")
+            .await;
+    }
+
+    command
+        .send_text(
+            ctx.http(),
+            format!(
+                "Session started for {} on {platform}. Use `/session end` when you are done playing",
+                user.name
+            )
+            .as_str(),
+        )
+        .await
+}
+
+async fn end<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
+where
+    Ctx: DiscordContext + Send + Sync,
+    Cmd: DiscordAppCmd + 'static + Send + Sync,
+{
+    let user = command.get_user_from_command_or_default();
+    let sessions = ctx
+        .data()
+        .read()
+        .await
+        .get::<PlaySessions>()
+        .expect("always registered")
+        .clone();
+
+    let started = sessions.read().await.get(&user.id);
+    let started = match started {
+        Some(started) => started,
+        None => {
+            return command
+                .send_text(
+                    ctx.http(),
+                    "You have no session running. Start one with `/session start`",
+                )
+                .await
+        }
+    };
+    tracing::info!("Ending the session of {}", user.name);
+
+    // The session is kept if fetching fails, so it can be ended again.
+    let ended = {
+        let siege_client = siege_client(ctx, command).await?;
+        match snapshot(siege_client.as_ref(), started.player_id, started.platform).await {
+            Ok(snapshot) => snapshot,
+            Err(err) => {
+                tracing::error!("Failed to fetch data: {err:?}");
+                return command.send_text(ctx.http(), "Failed to fetch data").await;
+            }
+        }
+    };
+
+    if let Err(err) = sessions.write().await.remove(&user.id) {
+        tracing::error!("Failed to remove the session: {err:?}");
+    }
+
+    let summary = started.summary(&ended);
+    let mut embed = CreateEmbed::default();
+    embed
+        .format(&summary)
+        .title(format!("Session of {} on {}", user.name, started.platform));
+    if summary.matches() == 0 {
+        embed.footer(|footer| {
+            footer.text("Ubisoft can take a few minutes to update the statistics after a match")
+        });
+    }
+
+    command.send_embedded(ctx.http(), embed).await
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use mockall::predicate::*;
+    use serenity::{model::user::User, prelude::RwLock};
+
+    use crate::{
+        commands::{
+            context::MockDiscordContext,
+            discord_app_command::MockDiscordAppCmd,
+            test::{register_client_in_type_map, MockSiegeClient},
+        },
+        play_session::{test::snapshot, MockPlaySessionLookup},
+    };
+
+    use super::*;
+
+    fn read_response<T: serde::de::DeserializeOwned>(filename: &str) -> T {
+        let content = std::fs::read_to_string(filename).unwrap();
+        serde_json::from_str(content.as_str()).unwrap()
+    }
+
+    async fn register_sessions(
+        ctx: &mut MockDiscordContext,
+        sessions: MockPlaySessionLookup,
+        client: MockSiegeClient,
+    ) {
+        register_client_in_type_map(ctx, client).await;
+        ctx.data()
+            .write()
+            .await
+            .insert::<PlaySessions>(Arc::new(RwLock::new(sessions)));
+    }
+
+    fn create_command(subcommand: &str, user: &User) -> MockDiscordAppCmd {
+        let mut command = MockDiscordAppCmd::new();
+        let subcommand = subcommand.to_string();
+        command
+            .expect_subcommand()
+            .returning(move || Some(subcommand.clone()));
+        command
+            .expect_get_user_from_command_or_default()
+            .return_const(user.clone());
+        command.expect_guild_id().return_const(None);
+        command
+    }
+
+    #[test]
+    fn validate_register() {
+        let mut command = CreateApplicationCommand::default();
+        let command = SessionCommand::register(&mut command);
+
+        assert_eq!(command.0.get("name").unwrap(), "session");
+
+        let options = command.0.get("options").unwrap().as_array().unwrap();
+        assert_eq!(options.len(), 2);
+        assert_eq!(options[0].get("name").unwrap(), START);
+        assert_eq!(options[1].get("name").unwrap(), END);
+        // Corresponds to `CommandOptionType::SubCommand`
+        assert_eq!(options[0].get("type").unwrap().as_u64().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn validate_run_start() {
+        let user = User::default();
+        let siege_id = Uuid::new_v4();
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        ctx.expect_lookup_siege_player::<MockDiscordAppCmd>()
+            .with(always(), eq(user.clone()))
+            .once()
+            .returning(move |_, _| Ok(siege_id));
+
+        let mut sessions = MockPlaySessionLookup::new();
+        sessions.expect_get().return_const(None);
+        sessions
+            .expect_insert()
+            .once()
+            .withf(move |user_id, snapshot| {
+                *user_id == User::default().id
+                    && snapshot.player_id == siege_id
+                    && snapshot.platform == PlatformFamily::Pc
+            })
+            .returning(|_, _| Ok(()));
+
+        let mut mock_client = MockSiegeClient::default();
+        mock_client
+            .expect_get_full_profiles()
+            .once()
+            .returning(|_| Ok(read_response("../samples/full_profile.json")));
+        mock_client
+            .expect_get_operators()
+            .once()
+            .returning(|_| Ok(read_response("../samples/operators.json")));
+        register_sessions(&mut ctx, sessions, mock_client).await;
+
+        let mut command = create_command(START, &user);
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_send_text()
+            .once()
+            .with(
+                always(),
+                eq(format!(
+                    "Session started for {} on Pc. Use `/session end` when you are done playing",
+                    user.name
+                )),
+            )
+            .returning(|_, _| Ok(()));
+
+        assert!(SessionCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_start_already_running() {
+        let user = User::default();
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let started = snapshot(20, 100, None);
+        let mut sessions = MockPlaySessionLookup::new();
+        sessions.expect_get().return_const(Some(started.clone()));
+        register_sessions(&mut ctx, sessions, MockSiegeClient::default()).await;
+
+        let mut command = create_command(START, &user);
+        command
+            .expect_send_text()
+            .once()
+            .with(
+                always(),
+                eq(format!(
+                    "Your session started <t:{}:R>. End it with `/session end` first",
+                    started.taken.timestamp()
+                )),
+            )
+            .returning(|_, _| Ok(()));
+
+        assert!(SessionCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_end() {
+        let user = User::default();
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut sessions = MockPlaySessionLookup::new();
+        sessions
+            .expect_get()
+            .return_const(Some(snapshot(0, 0, Some(1250))));
+        sessions
+            .expect_remove()
+            .once()
+            .with(eq(user.id))
+            .returning(|_| Ok(()));
+
+        let mut mock_client = MockSiegeClient::default();
+        mock_client
+            .expect_get_full_profiles()
+            .once()
+            .with(eq(Uuid::nil()))
+            .returning(|_| Ok(read_response("../samples/full_profile.json")));
+        mock_client
+            .expect_get_operators()
+            .once()
+            .returning(|_| Ok(read_response("../samples/operators.json")));
+        register_sessions(&mut ctx, sessions, mock_client).await;
+
+        let mut command = create_command(END, &user);
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(|_, embed| {
+                let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+                fields.iter().any(|x| {
+                    x.get("name").unwrap() == "Rank points"
+                        && x.get("value").unwrap() == "**+50** RP"
+                })
+            })
+            .returning(|_, _| Ok(()));
+
+        assert!(SessionCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_end_without_session() {
+        let user = User::default();
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut sessions = MockPlaySessionLookup::new();
+        sessions.expect_get().return_const(None);
+        sessions.expect_remove().never();
+        register_sessions(&mut ctx, sessions, MockSiegeClient::default()).await;
+
+        let mut command = create_command(END, &user);
+        command
+            .expect_send_text()
+            .once()
+            .with(
+                always(),
+                eq("You have no session running. Start one with `/session start`"),
+            )
+            .returning(|_, _| Ok(()));
+
+        assert!(SessionCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_end_api_failed() {
+        let user = User::default();
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut sessions = MockPlaySessionLookup::new();
+        sessions
+            .expect_get()
+            .return_const(Some(snapshot(20, 100, None)));
+        sessions.expect_remove().never();
+
+        let mut mock_client = MockSiegeClient::default();
+        mock_client
+            .expect_get_full_profiles()
+            .once()
+            .returning(|_| Err(siege_api::auth::ConnectError::InvalidPassword));
+        register_sessions(&mut ctx, sessions, mock_client).await;
+
+        let mut command = create_command(END, &user);
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("Failed to fetch data"))
+            .returning(|_, _| Ok(()));
+
+        assert!(SessionCommand::run(&ctx, &command).await.is_ok());
+    }
+}
@@ -0,0 +1,439 @@
+use async_trait::async_trait;
+use serenity::{
+    builder::{CreateApplicationCommand, CreateEmbed},
+    model::{
+        prelude::{
+            command::CommandOptionType, interaction::application_command::CommandDataOptionValue,
+            ChannelType, GuildId,
+        },
+        Permissions,
+    },
+    utils::Color,
+};
+use siege_api::models::{GameMode, GameModeOrAll, PlatformFamily};
+use strum::IntoEnumIterator;
+
+use crate::{
+    constants::{
+        ANNOUNCEMENT_CHANNEL, EPHEMERAL, GAME_MODE, MINIMUM_ROUNDS, PLATFORM, RESET,
+        STATISTICS_GAME_MODE,
+    },
+    guild_settings::{GuildSettings, Settings},
+    notification_channels::NotificationChannels,
+};
+
+use super::{
+    context::DiscordContext, defaults, discord_app_command::DiscordAppCmd, CmdResult,
+    CommandHandler,
+};
+
+pub struct SettingsCommand;
+
+#[async_trait]
+impl CommandHandler for SettingsCommand {
+    const NAME: &'static str = "settings";
+    const PERMISSIONS: Option<Permissions> = Some(Permissions::MANAGE_GUILD);
+
+    fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        command
+            .name(Self::NAME)
+            .description("Show or change the defaults of the commands in this server")
+            .dm_permission(false)
+            .create_option(|option| {
+                option
+                    .name(PLATFORM)
+                    .description("Platform of the statistics command")
+                    .kind(CommandOptionType::String)
+                    .required(false);
+
+                PlatformFamily::iter()
+                    .filter(|x| *x != PlatformFamily::Unknown)
+                    .for_each(|x| {
+                        option.add_string_choice(x, x);
+                    });
+
+                option
+            })
+            .create_option(|option| {
+                option
+                    .name(GAME_MODE)
+                    .description("Game mode of the operator and map statistics")
+                    .kind(CommandOptionType::String)
+                    .required(false);
+
+                GameModeOrAll::iter().for_each(|x| {
+                    option.add_string_choice(x, x);
+                });
+
+                option
+            })
+            .create_option(|option| {
+                option
+                    .name(STATISTICS_GAME_MODE)
+                    .description("Game mode of the statistics command")
+                    .kind(CommandOptionType::String)
+                    .required(false);
+
+                GameMode::iter()
+                    .filter(|x| *x != GameMode::Unknown)
+                    .for_each(|x| {
+                        option.add_string_choice(x, x);
+                    });
+
+                option
+            })
+            .create_option(|option| {
+                option
+                    .name(MINIMUM_ROUNDS)
+                    .description("Ignore operators played for less rounds in all_operators")
+                    .kind(CommandOptionType::Integer)
+                    .min_int_value(0)
+                    .required(false)
+            })
+            .create_option(|option| {
+                option
+                    .name(ANNOUNCEMENT_CHANNEL)
+                    .description("Channel to post changes of the server status in")
+                    .kind(CommandOptionType::Channel)
+                    .channel_types(&[ChannelType::Text])
+                    .required(false)
+            })
+            .create_option(|option| {
+                option
+                    .name(EPHEMERAL)
+                    .description("Only show the responses to the user running the command")
+                    .kind(CommandOptionType::Boolean)
+                    .required(false)
+            })
+            .create_option(|option| {
+                option
+                    .name(RESET)
+                    .description(
+                        "Reset the defaults to the ones of the bot before the other changes",
+                    )
+                    .kind(CommandOptionType::Boolean)
+                    .required(false)
+            })
+    }
+
+    async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
+    where
+        Ctx: DiscordContext + Send + Sync,
+        Cmd: DiscordAppCmd + 'static + Send + Sync,
+    {
+        let guild_id = match command.guild_id() {
+            Some(guild_id) => guild_id,
+            None => {
+                return command
+                    .send_text(ctx.http(), "Settings can only be changed in a server")
+                    .await
+            }
+        };
+
+        if let Err(err) = update_settings(ctx, command, guild_id).await {
+            tracing::error!("Failed to store settings: {err:?}");
+            return command
+                .send_text(ctx.http(), "Failed to update the settings")
+                .await;
+        }
+
+        let defaults = defaults(ctx, command).await;
+        let channel = {
+            let data = ctx.data().read().await;
+            let lookup = data
+                .get::<NotificationChannels>()
+                .expect("always registered");
+            let channel = lookup.read().await.get(&guild_id);
+            channel
+        };
+
+        let mut embed = CreateEmbed::default();
+        embed
+            .title("Settings of this server")
+            .color(Color::TEAL)
+            .field("Platform", defaults.platform, true)
+            .field("Game mode", defaults.game_mode, true)
+            .field("Statistics game mode", defaults.statistics_game_mode, true)
+            .field("Minimum rounds", defaults.minimum_rounds, true)
+            .field(
+                "Ephemeral",
+                if defaults.ephemeral { "Yes" } else { "No" },
+                true,
+            )
+            .field(
+                "Announcement channel",
+                channel.map_or("None".to_string(), |x| format!("<#{x}>")),
+                true,
+            );
+
+        command.send_embedded(ctx.http(), embed).await
+    }
+}
+
+/// Store the settings given in the options of the command. Nothing is stored
+/// if no options are given.
+async fn update_settings<Ctx, Cmd>(
+    ctx: &Ctx,
+    command: &Cmd,
+    guild_id: GuildId,
+) -> Result<(), std::io::Error>
+where
+    Ctx: DiscordContext,
+    Cmd: DiscordAppCmd,
+{
+    let data = ctx.data().read().await;
+
+    if let Some(CommandDataOptionValue::Channel(channel)) = command.get_option(ANNOUNCEMENT_CHANNEL)
+    {
+        tracing::info!("Setting notifications for {guild_id} to {}", channel.id);
+        data.get::<NotificationChannels>()
+            .expect("always registered")
+            .write()
+            .await
+            .insert(&guild_id, channel.id)?;
+    }
+
+    let mut lookup = data
+        .get::<GuildSettings>()
+        .expect("always registered")
+        .write()
+        .await;
+    let current = lookup.get(&guild_id);
+
+    let reset = matches!(
+        command.get_option(RESET),
+        Some(CommandDataOptionValue::Boolean(true))
+    );
+    let mut settings = if reset { Settings::default() } else { current };
+    if let Some(platform) = command.extract_enum_option(PLATFORM) {
+        settings.platform = Some(platform);
+    }
+    if let Some(game_mode) = command.extract_enum_option(GAME_MODE) {
+        settings.game_mode = Some(game_mode);
+    }
+    if let Some(game_mode) = command.extract_enum_option(STATISTICS_GAME_MODE) {
+        settings.statistics_game_mode = Some(game_mode);
+    }
+    if let Some(CommandDataOptionValue::Integer(rounds)) = command.get_option(MINIMUM_ROUNDS) {
+        settings.minimum_rounds = u64::try_from(rounds).ok();
+    }
+    if let Some(CommandDataOptionValue::Boolean(ephemeral)) = command.get_option(EPHEMERAL) {
+        settings.ephemeral = Some(ephemeral);
+    }
+
+    if settings == current {
+        return Ok(());
+    }
+
+    tracing::info!("Changing settings for {guild_id} to {settings:?}");
+    if settings == Settings::default() {
+        lookup.remove(&guild_id)
+    } else {
+        lookup.insert(&guild_id, settings)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use mockall::predicate::*;
+    use serenity::{
+        model::prelude::ChannelId,
+        prelude::{RwLock, TypeMap},
+    };
+
+    use crate::{
+        commands::{context::MockDiscordContext, discord_app_command::MockDiscordAppCmd},
+        config::Defaults,
+        guild_settings::MockSettingsLookup,
+        notification_channels::MockChannelLookup,
+    };
+
+    use super::*;
+
+    #[test]
+    fn validate_register() {
+        let mut command = CreateApplicationCommand::default();
+
+        // Act
+        let command = SettingsCommand::register(&mut command);
+
+        // Assert
+        assert_eq!(command.0.get("name").unwrap(), "settings");
+        let options = command.0.get("options").unwrap().as_array().unwrap();
+        assert_eq!(options.len(), 7);
+        assert_eq!(
+            options[0].get("choices").unwrap().as_array().unwrap().len(),
+            2
+        );
+        assert_eq!(
+            SettingsCommand::PERMISSIONS,
+            Some(Permissions::MANAGE_GUILD)
+        );
+    }
+
+    async fn register_lookups(
+        ctx: &mut MockDiscordContext,
+        settings: MockSettingsLookup,
+        channels: MockChannelLookup,
+    ) {
+        let data = Arc::new(RwLock::new(TypeMap::default()));
+        {
+            let mut data = data.write().await;
+            data.insert::<Defaults>(Defaults::default());
+            data.insert::<GuildSettings>(Arc::new(RwLock::new(settings)));
+            data.insert::<NotificationChannels>(Arc::new(RwLock::new(channels)));
+        }
+        ctx.expect_data().return_const(data);
+    }
+
+    fn create_command(guild_id: GuildId) -> MockDiscordAppCmd {
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(Some(guild_id));
+        command
+    }
+
+    fn field(embed: &CreateEmbed, name: &str) -> String {
+        embed
+            .0
+            .get("fields")
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|x| x.get("name").unwrap() == name)
+            .and_then(|x| x.get("value"))
+            .and_then(|x| x.as_str())
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn validate_run_without_options() {
+        let guild_id = GuildId::from(1);
+        let current = Settings {
+            minimum_rounds: Some(10),
+            ..Default::default()
+        };
+
+        let mut settings = MockSettingsLookup::new();
+        settings.expect_get().return_const(current);
+        settings.expect_insert().never();
+        let mut channels = MockChannelLookup::new();
+        channels.expect_get().return_const(Some(ChannelId::from(2)));
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        register_lookups(&mut ctx, settings, channels).await;
+
+        let mut command = create_command(guild_id);
+        command.expect_get_option().return_const(None);
+        command
+            .expect_extract_enum_option::<PlatformFamily>()
+            .return_const(None);
+        command
+            .expect_extract_enum_option::<GameModeOrAll>()
+            .return_const(None);
+        command
+            .expect_extract_enum_option::<GameMode>()
+            .return_const(None);
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(|_, embed| {
+                field(embed, "Minimum rounds") == "10"
+                    && field(embed, "Platform") == "Pc"
+                    && field(embed, "Announcement channel") == "<#2>"
+            })
+            .returning(|_, _| Ok(()));
+
+        assert!(SettingsCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_change_and_reset() {
+        let guild_id = GuildId::from(1);
+        let expected = Settings {
+            game_mode: Some(GameModeOrAll::Ranked),
+            ephemeral: Some(true),
+            ..Default::default()
+        };
+
+        let mut settings = MockSettingsLookup::new();
+        let mut sequence = mockall::Sequence::new();
+        settings
+            .expect_get()
+            .once()
+            .in_sequence(&mut sequence)
+            .return_const(Settings {
+                minimum_rounds: Some(10),
+                ..Default::default()
+            });
+        settings
+            .expect_insert()
+            .with(eq(guild_id), eq(expected))
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(()));
+        settings
+            .expect_get()
+            .once()
+            .in_sequence(&mut sequence)
+            .return_const(expected);
+        let mut channels = MockChannelLookup::new();
+        channels.expect_get().return_const(None);
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        register_lookups(&mut ctx, settings, channels).await;
+
+        let mut command = create_command(guild_id);
+        command
+            .expect_get_option()
+            .with(eq(RESET))
+            .return_const(Some(CommandDataOptionValue::Boolean(true)));
+        command
+            .expect_get_option()
+            .with(eq(EPHEMERAL))
+            .return_const(Some(CommandDataOptionValue::Boolean(true)));
+        command.expect_get_option().return_const(None);
+        command
+            .expect_extract_enum_option::<PlatformFamily>()
+            .return_const(None);
+        command
+            .expect_extract_enum_option::<GameModeOrAll>()
+            .return_const(Some(GameModeOrAll::Ranked));
+        command
+            .expect_extract_enum_option::<GameMode>()
+            .return_const(None);
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(|_, embed| {
+                field(embed, "Minimum rounds") == "0"
+                    && field(embed, "Game mode") == "Ranked"
+                    && field(embed, "Ephemeral") == "Yes"
+                    && field(embed, "Announcement channel") == "None"
+            })
+            .returning(|_, _| Ok(()));
+
+        assert!(SettingsCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_in_direct_message() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("Settings can only be changed in a server"))
+            .returning(|_, _| Ok(()));
+
+        assert!(SettingsCommand::run(&ctx, &command).await.is_ok());
+    }
+}
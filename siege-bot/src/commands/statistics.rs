@@ -12,21 +12,23 @@ use strum::IntoEnumIterator;
 
 use crate::{
     constants::{GAME_MODE, PLATFORM},
-    SiegeApi,
+    formatting::{decimal, percentage},
 };
 
 use super::{
-    context::DiscordContext, discord_app_command::DiscordAppCmd, AddUserOptionToCommand, CmdResult,
-    CommandHandler,
+    context::DiscordContext, defaults, discord_app_command::DiscordAppCmd, siege_client,
+    AddUserOptionToCommand, CmdResult, CommandHandler,
 };
 
 pub struct StatisticsCommand;
 
 #[async_trait]
 impl CommandHandler for StatisticsCommand {
+    const NAME: &'static str = "statistics";
+
     fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
         command
-            .name("statistics")
+            .name(Self::NAME)
             .description("Get the statistics for a Siege player")
             .create_option(|option| {
                 option
@@ -35,9 +37,11 @@ impl CommandHandler for StatisticsCommand {
                     .kind(CommandOptionType::String)
                     .required(false);
 
-                GameMode::iter().for_each(|x| {
-                    option.add_string_choice(x.to_string(), x.to_string());
-                });
+                GameMode::iter()
+                    .filter(|x| *x != GameMode::Unknown)
+                    .for_each(|x| {
+                        option.add_string_choice(x.to_string(), x.to_string());
+                    });
 
                 option
             })
@@ -48,9 +52,11 @@ impl CommandHandler for StatisticsCommand {
                     .kind(CommandOptionType::String)
                     .required(false);
 
-                PlatformFamily::iter().for_each(|x| {
-                    option.add_string_choice(x.to_string(), x.to_string());
-                });
+                PlatformFamily::iter()
+                    .filter(|x| *x != PlatformFamily::Unknown)
+                    .for_each(|x| {
+                        option.add_string_choice(x.to_string(), x.to_string());
+                    });
 
                 option
             })
@@ -64,12 +70,13 @@ impl CommandHandler for StatisticsCommand {
     {
         let user = command.get_user_from_command_or_default();
         let player_id = ctx.lookup_siege_player(command, &user).await?;
+        let defaults = defaults(ctx, command).await;
         let platform = command
             .extract_enum_option(PLATFORM)
-            .unwrap_or(PlatformFamily::Pc);
+            .unwrap_or(defaults.platform);
         let game_mode = command
             .extract_enum_option(GAME_MODE)
-            .unwrap_or(GameMode::Casual);
+            .unwrap_or(defaults.statistics_game_mode);
 
         tracing::info!(
             "Getting statistics for {} on {platform} in mode {game_mode}",
@@ -77,10 +84,7 @@ impl CommandHandler for StatisticsCommand {
         );
 
         let statistics = {
-            let data = ctx.data().read().await;
-            let siege_client = data
-                .get::<SiegeApi>()
-                .expect("Siege client is always registered");
+            let siege_client = siege_client(ctx, command).await?;
             match siege_client.get_full_profiles(player_id).await {
                 Ok(data) => data,
                 Err(err) => {
@@ -102,8 +106,8 @@ impl CommandHandler for StatisticsCommand {
                         .field(
                             "Kill/death",
                             format!(
-                                "K/D: **{kd:.2}** - Kills {kills} / Deaths {deaths}",
-                                kd = season.kd(),
+                                "K/D: **{kd}** - Kills {kills} / Deaths {deaths}",
+                                kd = decimal(season.kd()),
                                 kills = season.kills(),
                                 deaths = season.deaths(),
                             ),
@@ -112,11 +116,11 @@ impl CommandHandler for StatisticsCommand {
                         .field(
                             "Match",
                             format!(
-                                "Matches {total} - **{win_rate:.2} %** - Wins **{wins}** / Losses **{losses}**",
+                                "Matches {total} - **{win_rate}** - Wins **{wins}** / Losses **{losses}**",
                                 total = matches.total_matches(),
                                 wins = matches.wins(),
                                 losses = matches.losses(),
-                                win_rate = matches.win_rate() * 100.0,
+                                win_rate = percentage(matches.win_rate()),
                             ),
                             false,
                         );
@@ -183,14 +187,14 @@ mod test {
 
         let options = command.0.get("options").unwrap().as_array().unwrap();
 
-        let opt = options.get(0).unwrap();
+        let opt = options.first().unwrap();
         assert_eq!(opt.get("name").unwrap(), GAME_MODE);
         assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
         assert_eq!(opt.get("type").unwrap().as_u64().unwrap(), 3);
         assert!(!opt.get("description").unwrap().as_str().unwrap().is_empty());
         assert_eq!(
             opt.get("choices").unwrap().as_array().unwrap().len(),
-            GameMode::iter().len()
+            GameMode::iter().len() - 1
         );
 
         let opt = options.get(1).unwrap();
@@ -212,7 +216,7 @@ mod test {
         let user = User::default();
         let siege_id = Uuid::new_v4();
 
-        for game_mode in vec![
+        for game_mode in [
             GameMode::Casual,
             GameMode::Event,
             GameMode::Ranked,
@@ -237,6 +241,8 @@ mod test {
             register_client_in_type_map(&mut ctx, mock_client).await;
 
             let mut command = MockDiscordAppCmd::new();
+            command.expect_guild_id().return_const(None);
+            command.expect_defer().once().returning(|_| Ok(()));
             command
                 .expect_get_user_from_command_or_default()
                 .return_const(user.clone());
@@ -280,6 +286,8 @@ mod test {
         register_client_in_type_map(&mut ctx, mock_client).await;
 
         let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command.expect_defer().once().returning(|_| Ok(()));
         command
             .expect_get_user_from_command_or_default()
             .return_const(user.clone());
@@ -326,6 +334,8 @@ mod test {
         register_client_in_type_map(&mut ctx, mock_client).await;
 
         let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command.expect_defer().once().returning(|_| Ok(()));
         command
             .expect_get_user_from_command_or_default()
             .return_const(user.clone());
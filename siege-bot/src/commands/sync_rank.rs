@@ -0,0 +1,234 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serenity::builder::CreateApplicationCommand;
+
+use crate::{
+    config::RankRolesConfig,
+    rank_roles::{current_rank, update_roles},
+};
+
+use super::{
+    context::DiscordContext, defaults, discord_app_command::DiscordAppCmd, siege_client, CmdResult,
+    CommandHandler,
+};
+
+pub struct SyncRankCommand;
+
+#[async_trait]
+impl CommandHandler for SyncRankCommand {
+    const NAME: &'static str = "sync_rank";
+    const COOLDOWN: Option<Duration> = Some(Duration::from_secs(60));
+
+    fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        command
+            .name(Self::NAME)
+            .description("Update your rank role in this server to your current rank")
+            .dm_permission(false)
+    }
+
+    async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
+    where
+        Ctx: DiscordContext + Send + Sync,
+        Cmd: DiscordAppCmd + 'static + Send + Sync,
+    {
+        let guild_id = match command.guild_id() {
+            Some(guild_id) => guild_id,
+            None => {
+                return command
+                    .send_text(ctx.http(), "Rank roles can only be synced in a server")
+                    .await
+            }
+        };
+        let roles = {
+            let data = ctx.data().read().await;
+            data.get::<RankRolesConfig>()
+                .expect("always registered")
+                .guilds
+                .get(&guild_id)
+                .cloned()
+        };
+        let roles = match roles {
+            Some(roles) => roles,
+            None => {
+                return command
+                    .send_text(ctx.http(), "Rank roles are not set up in this server")
+                    .await
+            }
+        };
+
+        let user = command.get_user_from_command_or_default();
+        let player_id = ctx.lookup_siege_player(command, &user).await?;
+        let platform = defaults(ctx, command).await.platform;
+        tracing::info!("Syncing the rank role of {} in {guild_id}", user.name);
+
+        let profiles = {
+            let siege_client = siege_client(ctx, command).await?;
+            match siege_client.get_full_profiles(player_id).await {
+                Ok(data) => data,
+                Err(err) => {
+                    tracing::error!("Failed to fetch data: {err:?}");
+                    return command.send_text(ctx.http(), "Failed to fetch data").await;
+                }
+            }
+        };
+        let rank = current_rank(&profiles, platform);
+
+        let http = ctx
+            .http()
+            .expect("http should always be set when updating roles");
+        let result = match guild_id.member(&http, user.id).await {
+            Ok(mut member) => update_roles(&http, &mut member, &roles, rank).await,
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(changes) if changes.is_empty() => {
+                command
+                    .send_text(
+                        ctx.http(),
+                        format!("{} is **{rank}**, the roles are up to date", user.name).as_str(),
+                    )
+                    .await
+            }
+            Ok(_) => {
+                command
+                    .send_text(
+                        ctx.http(),
+                        format!("{} is **{rank}**, the roles have been updated", user.name)
+                            .as_str(),
+                    )
+                    .await
+            }
+            Err(err) => {
+                tracing::error!("Failed to update rank roles: {err:?}");
+                command
+                    .send_text(
+                        ctx.http(),
+                        "Failed to update the roles. The bot needs the Manage Roles permission and a role above the rank roles",
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, sync::Arc};
+
+    use mockall::predicate::*;
+    use serenity::{
+        model::{
+            prelude::{GuildId, RoleId},
+            user::User,
+        },
+        prelude::{RwLock, TypeMap},
+    };
+    use siege_api::data::rank::RankTier;
+    use uuid::Uuid;
+
+    use crate::{
+        commands::{
+            context::MockDiscordContext,
+            discord_app_command::MockDiscordAppCmd,
+            test::{register_client_in_type_map, MockSiegeClient},
+        },
+        guild_settings::{GuildSettings, MockSettingsLookup, Settings},
+    };
+
+    use super::*;
+
+    #[test]
+    fn validate_register() {
+        let mut command = CreateApplicationCommand::default();
+        let command = SyncRankCommand::register(&mut command);
+
+        assert_eq!(command.0.get("name").unwrap(), "sync_rank");
+        assert_eq!(SyncRankCommand::COOLDOWN, Some(Duration::from_secs(60)));
+    }
+
+    #[tokio::test]
+    async fn validate_run_in_direct_message() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("Rank roles can only be synced in a server"))
+            .returning(|_, _| Ok(()));
+
+        assert!(SyncRankCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_without_roles() {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        let data = Arc::new(RwLock::new(TypeMap::default()));
+        data.write()
+            .await
+            .insert::<RankRolesConfig>(RankRolesConfig::default());
+        ctx.expect_data().return_const(data);
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(Some(GuildId(1)));
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("Rank roles are not set up in this server"))
+            .returning(|_, _| Ok(()));
+
+        assert!(SyncRankCommand::run(&ctx, &command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_run_api_failed() {
+        let user = User::default();
+        let siege_id = Uuid::new_v4();
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        ctx.expect_lookup_siege_player::<MockDiscordAppCmd>()
+            .with(always(), eq(user.clone()))
+            .once()
+            .returning(move |_, _| Ok(siege_id));
+
+        let mut mock_client = MockSiegeClient::default();
+        mock_client
+            .expect_get_full_profiles()
+            .once()
+            .with(eq(siege_id))
+            .returning(|_| Err(siege_api::auth::ConnectError::InvalidPassword));
+        register_client_in_type_map(&mut ctx, mock_client).await;
+        {
+            let data = ctx.data().clone();
+            let mut data = data.write().await;
+            data.insert::<RankRolesConfig>(RankRolesConfig {
+                guilds: HashMap::from([(GuildId(1), HashMap::from([(RankTier::Gold, RoleId(2))]))]),
+                ..Default::default()
+            });
+
+            let mut settings = MockSettingsLookup::new();
+            settings.expect_get().return_const(Settings::default());
+            data.insert::<GuildSettings>(Arc::new(RwLock::new(settings)));
+        }
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(Some(GuildId(1)));
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_get_user_from_command_or_default()
+            .return_const(user.clone());
+        command
+            .expect_send_text()
+            .once()
+            .with(always(), eq("Failed to fetch data"))
+            .returning(|_, _| Ok(()));
+
+        assert!(SyncRankCommand::run(&ctx, &command).await.is_ok());
+    }
+}
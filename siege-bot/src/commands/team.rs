@@ -0,0 +1,242 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serenity::{
+    builder::{CreateApplicationCommand, CreateEmbed},
+    utils::Color,
+};
+use siege_api::game_models::Side;
+
+use crate::{
+    constants::GAME_MODE,
+    formatting::FormatEmbedded,
+    team::{analyze_side, top_operators, PlayerMains},
+};
+
+use super::{
+    context::DiscordContext, defaults, discord_app_command::DiscordAppCmd, join_names, lookup_team,
+    siege_client, AddUserOptionToCommand, CmdResult, CommandHandler,
+};
+
+/// Number of operators per side considered the mains of a player.
+const MAINS_PER_PLAYER: usize = 3;
+
+pub struct TeamCommand;
+
+#[async_trait]
+impl CommandHandler for TeamCommand {
+    const NAME: &'static str = "team";
+    const COOLDOWN: Option<Duration> = Some(Duration::from_secs(30));
+
+    fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        command
+            .name(Self::NAME)
+            .description("Show the operator mains of a team per side and the roles they cover")
+            .add_team_options()
+            .add_game_mode_option()
+    }
+
+    async fn run<Ctx, Cmd>(ctx: &Ctx, command: &Cmd) -> CmdResult
+    where
+        Ctx: DiscordContext + Send + Sync,
+        Cmd: DiscordAppCmd + 'static + Send + Sync,
+    {
+        let game_mode = command
+            .extract_enum_option(GAME_MODE)
+            .unwrap_or(defaults(ctx, command).await.game_mode);
+
+        let (players, missing) = lookup_team(ctx, command).await;
+        if players.is_empty() {
+            return command
+                .send_text(
+                    ctx.http(),
+                    "None of the users have linked a Siege player.\nUse the `/add` command to link a Discord profile to an Ubisoft name",
+                )
+                .await;
+        }
+
+        tracing::info!(
+            "Analyzing the team composition of {}",
+            join_names(players.iter().map(|(user, _)| user))
+        );
+
+        let responses = {
+            let siege_client = siege_client(ctx, command).await?;
+
+            let mut responses = Vec::with_capacity(players.len());
+            for (user, player_id) in &players {
+                match siege_client.get_operators(*player_id).await {
+                    Ok(data) => responses.push((user, data)),
+                    Err(err) => {
+                        tracing::error!("Failed to fetch data for {}: {err:?}", user.name)
+                    }
+                }
+            }
+            responses
+        };
+
+        if responses.is_empty() {
+            return command.send_text(ctx.http(), "Failed to fetch data").await;
+        }
+
+        let mut embed = CreateEmbed::default();
+        embed
+            .title(format!(
+                "Team composition for {}",
+                join_names(responses.iter().map(|(user, _)| *user))
+            ))
+            .color(Color::DARK_GREEN);
+        for side in [Side::Attacker, Side::Defender] {
+            let mains = responses
+                .iter()
+                .map(|(user, response)| PlayerMains {
+                    name: user.name.clone(),
+                    operators: top_operators(response, game_mode, side, MAINS_PER_PLAYER),
+                })
+                .collect();
+            embed.format(&analyze_side(side, mains));
+        }
+        if !missing.is_empty() {
+            embed.footer(|footer| {
+                footer.text(format!(
+                    "No Siege player linked for {}",
+                    join_names(&missing)
+                ))
+            });
+        }
+
+        command.send_embedded(ctx.http().clone(), embed).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use mockall::predicate::*;
+    use serde_json::Value;
+    use serenity::{
+        model::{
+            prelude::{interaction::application_command::CommandDataOptionValue, UserId},
+            user::User,
+        },
+        prelude::{RwLock, TypeMap},
+    };
+    use siege_api::models::{GameModeOrAll, StatisticResponse};
+    use uuid::Uuid;
+
+    use crate::{
+        commands::{
+            context::MockDiscordContext, discord_app_command::MockDiscordAppCmd,
+            test::MockSiegeClient,
+        },
+        config::Defaults,
+        constants::PLAYERS,
+        siege_player_lookup::{MockPlayerLookup, SiegePlayerLookup},
+        SiegeApi,
+    };
+
+    use super::*;
+
+    static FIRST_ID: Uuid = Uuid::from_u128(1);
+    static SECOND_ID: Uuid = Uuid::from_u128(2);
+
+    fn load_operators() -> StatisticResponse {
+        let content = std::fs::read_to_string("../samples/operators.json").unwrap();
+        serde_json::from_str(content.as_str()).unwrap()
+    }
+
+    #[test]
+    fn validate_register() {
+        let mut command = CreateApplicationCommand::default();
+        let command = TeamCommand::register(&mut command);
+
+        // Assert
+        assert_eq!(command.0.get("name").unwrap(), "team");
+        assert!(!command
+            .0
+            .get("description")
+            .and_then(|x| x.as_str())
+            .unwrap()
+            .is_empty());
+
+        let options = command.0.get("options").unwrap().as_array().unwrap();
+        assert_eq!(options.len(), PLAYERS.len() + 1);
+        PLAYERS.iter().zip(options).for_each(|(name, opt)| {
+            assert_eq!(opt.get("name").unwrap(), name);
+            assert_eq!(*opt.get("required").unwrap(), Value::Bool(false));
+        });
+        assert_eq!(options.get(5).unwrap().get("name").unwrap(), GAME_MODE);
+    }
+
+    fn create_user(id: u64, name: &str) -> User {
+        let mut user = User::default();
+        user.id = UserId(id);
+        user.name = name.to_string();
+        user
+    }
+
+    #[tokio::test]
+    async fn validate_run() {
+        let users = [create_user(1, "First"), create_user(2, "Second")];
+
+        let mut lookup = MockPlayerLookup::default();
+        lookup.expect_get().returning(|id| match id.0 {
+            1 => Some(&FIRST_ID),
+            2 => Some(&SECOND_ID),
+            _ => None,
+        });
+
+        let mut client = MockSiegeClient::default();
+        client
+            .expect_get_operators()
+            .times(2)
+            .returning(|_| Ok(load_operators()));
+
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        let data = Arc::new(RwLock::new(TypeMap::default()));
+        {
+            let mut data = data.write().await;
+            data.insert::<SiegeApi>(Arc::new(client));
+            data.insert::<SiegePlayerLookup>(Arc::new(RwLock::new(lookup)));
+            data.insert::<Defaults>(Defaults::default());
+        }
+        ctx.expect_data().return_const(data);
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_guild_id().return_const(None);
+        command.expect_defer().once().returning(|_| Ok(()));
+        command
+            .expect_extract_enum_option::<GameModeOrAll>()
+            .with(eq(GAME_MODE))
+            .return_const(None);
+        PLAYERS.iter().enumerate().for_each(|(i, name)| {
+            command.expect_get_option().with(eq(*name)).return_const(
+                users
+                    .get(i)
+                    .map(|user| CommandDataOptionValue::User(user.clone(), None)),
+            );
+        });
+        command
+            .expect_send_embedded()
+            .once()
+            .withf(|_, embed| {
+                let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+                let names = fields
+                    .iter()
+                    .map(|x| x.get("name").unwrap().as_str().unwrap())
+                    .collect::<Vec<_>>();
+
+                embed.0.get("title").unwrap() == "Team composition for First, Second"
+                    && !embed.0.contains_key("footer")
+                    && names.contains(&"Attacker mains of First")
+                    && names.contains(&"Defender mains of Second")
+                    && names.contains(&"Defender role coverage")
+                    && names.contains(&"Attacker gaps")
+            })
+            .returning(|_, _| Ok(()));
+
+        assert!(TeamCommand::run(&ctx, &command).await.is_ok());
+    }
+}
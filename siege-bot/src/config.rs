@@ -0,0 +1,752 @@
+//! Configuration of the bot, read from a TOML file and overridden by
+//! environment variables.
+//!
+//! Every setting has a default, except for the Discord token and the Ubisoft
+//! credentials. These are usually only set in the environment, so the file is
+//! optional. The configuration is validated once at startup, so the bot stops
+//! with an error message instead of panicking once a setting is used.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::read_to_string,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use chrono::{DateTime, Datelike, TimeZone, Utc, Weekday};
+use serde::{Deserialize, Deserializer};
+use serenity::model::prelude::{ChannelId, GuildId, RoleId};
+use siege_api::{
+    data::rank::RankTier,
+    models::{GameMode, GameModeOrAll, PlatformFamily},
+};
+use thiserror::Error;
+use tracing_subscriber::filter::{Directive, LevelFilter};
+
+/// File read when `SIEGE_BOT_CONFIG` is not set.
+pub const DEFAULT_CONFIG_FILE: &str = "siege-bot.toml";
+
+/// Directory the linked players and notification channels were read from
+/// before the storage directory could be configured.
+const LEGACY_STORAGE_DIR: &str = "/config";
+
+/// Environment variables overriding a setting, with the path of the setting.
+const ENV_OVERRIDES: [(&str, &[&str]); 11] = [
+    ("DISCORD_TOKEN", &["discord", "token"]),
+    ("UBISOFT_EMAIL", &["ubisoft", "email"]),
+    ("UBISOFT_PASSWORD", &["ubisoft", "password"]),
+    ("STORAGE_BACKEND", &["storage", "backend"]),
+    ("STORAGE_DIR", &["storage", "directory"]),
+    ("LOGS_DIR", &["logging", "directory"]),
+    ("LOG_LEVEL", &["logging", "level"]),
+    ("DEFAULT_PLATFORM", &["defaults", "platform"]),
+    ("DEFAULT_GAME_MODE", &["defaults", "game_mode"]),
+    ("COMMAND_REGISTRATION", &["commands", "registration"]),
+    ("DIGEST_WEEKDAY", &["digest", "weekday"]),
+];
+
+/// Environment variables overriding a numeric setting.
+const NUMERIC_ENV_OVERRIDES: [(&str, &[&str]); 4] = [
+    ("PAGINATION_TTL", &["cache", "pagination_ttl"]),
+    ("STATUS_POLL_INTERVAL", &["cache", "status_poll_interval"]),
+    ("RANK_SYNC_INTERVAL", &["rank_roles", "sync_interval"]),
+    ("DIGEST_HOUR", &["digest", "hour"]),
+];
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to read `{0}`: {1}")]
+    Read(PathBuf, std::io::Error),
+    #[error("invalid configuration: {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("`{0}` must be set, either in the configuration file or with `{1}`")]
+    Missing(&'static str, &'static str),
+    #[error("`{0}` is invalid: {1}")]
+    Invalid(&'static str, String),
+    #[error("`{0}` in `commands.enabled` is not a command")]
+    UnknownCommand(String),
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub discord: DiscordConfig,
+    pub ubisoft: UbisoftConfig,
+    pub storage: StorageConfig,
+    pub logging: LoggingConfig,
+    pub commands: CommandsConfig,
+    pub defaults: Defaults,
+    pub cache: CacheConfig,
+    pub notifications: NotificationsConfig,
+    pub rank_roles: RankRolesConfig,
+    pub digest: DigestConfig,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DiscordConfig {
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UbisoftConfig {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    /// JSON files in the storage directory.
+    #[default]
+    Json,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    /// Directory the linked players and notification channels are stored in.
+    pub directory: PathBuf,
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        Self {
+            backend: StorageBackend::Json,
+            directory: PathBuf::from("."),
+        }
+    }
+}
+
+impl StorageConfig {
+    /// Path of a file in the storage directory.
+    pub fn file(&self, filename: &str) -> PathBuf {
+        self.directory.join(filename)
+    }
+
+    /// Read a stored file. A file which does not exist yet is read from the
+    /// legacy `/config` directory instead, if it is there, and is written to
+    /// the storage directory on the next change.
+    pub fn read(path: &Path) -> std::io::Result<String> {
+        read_with_legacy_fallback(path, Path::new(LEGACY_STORAGE_DIR))
+    }
+}
+
+fn read_with_legacy_fallback(path: &Path, legacy_directory: &Path) -> std::io::Result<String> {
+    let legacy = path.file_name().map(|name| legacy_directory.join(name));
+    match (read_to_string(path), legacy) {
+        (Err(err), Some(legacy))
+            if err.kind() == ErrorKind::NotFound && legacy != path && legacy.exists() =>
+        {
+            tracing::warn!(
+                "Reading `{}` from the legacy location `{}`",
+                path.display(),
+                legacy.display()
+            );
+            read_to_string(legacy)
+        }
+        (result, _) => result,
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoggingConfig {
+    pub directory: PathBuf,
+    /// Level of the logs from the bot itself.
+    #[serde(deserialize_with = "from_str")]
+    pub level: LevelFilter,
+    /// Additional filter directives, e.g. `siege_api=info`.
+    pub directives: Vec<String>,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            directory: PathBuf::from("./logs/"),
+            level: LevelFilter::DEBUG,
+            directives: vec!["siege_api=info".to_string()],
+        }
+    }
+}
+
+impl LoggingConfig {
+    /// All filter directives, starting with the level of the bot.
+    pub fn filter_directives(&self) -> Result<Vec<Directive>, ConfigError> {
+        std::iter::once(format!("siege_bot={}", self.level))
+            .chain(self.directives.iter().cloned())
+            .map(|x| {
+                x.parse().map_err(|err| {
+                    ConfigError::Invalid("logging.directives", format!("{x}: {err}"))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Where the commands are registered with Discord.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Registration {
+    /// Registered once for all guilds. Discord can take a while to show
+    /// changes to global commands.
+    Global,
+    /// Registered in each guild, where changes are shown immediately.
+    #[default]
+    Guild,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CommandsConfig {
+    /// Names of the commands to register. All commands are registered if not
+    /// set.
+    pub enabled: Option<Vec<String>>,
+    pub registration: Registration,
+    /// Guilds the commands are registered in with guild registration, e.g.
+    /// guilds used for development. All guilds if empty.
+    pub guilds: Vec<GuildId>,
+}
+
+impl CommandsConfig {
+    /// Whether the commands should be registered in the guild. Guilds where
+    /// they should not be have any commands left from earlier runs removed.
+    pub fn registers_in_guild(&self, guild_id: GuildId) -> bool {
+        match self.registration {
+            Registration::Global => false,
+            Registration::Guild => self.guilds.is_empty() || self.guilds.contains(&guild_id),
+        }
+    }
+}
+
+/// Options used when they are not given to a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Defaults {
+    pub platform: PlatformFamily,
+    /// Game mode of the commands using the `playerstats` endpoint.
+    #[serde(deserialize_with = "from_str")]
+    pub game_mode: GameModeOrAll,
+    /// Game mode of the `statistics` command, which shows the seasonal profile.
+    #[serde(deserialize_with = "from_str")]
+    pub statistics_game_mode: GameMode,
+    /// Operators played for fewer rounds are left out of `all_operators`.
+    pub minimum_rounds: u64,
+    /// Only show the responses to the user running the command.
+    pub ephemeral: bool,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Self {
+            platform: PlatformFamily::Pc,
+            game_mode: GameModeOrAll::All,
+            statistics_game_mode: GameMode::Casual,
+            minimum_rounds: 0,
+            ephemeral: false,
+        }
+    }
+}
+
+impl serenity::prelude::TypeMapKey for Defaults {
+    type Value = Defaults;
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Seconds the pages of a paginated message are kept after the buttons
+    /// were last used.
+    pub pagination_ttl: u64,
+    /// Seconds between polling the game status.
+    pub status_poll_interval: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            pagination_ttl: 10 * 60,
+            status_poll_interval: 5 * 60,
+        }
+    }
+}
+
+impl CacheConfig {
+    pub fn pagination_ttl(&self) -> Duration {
+        Duration::from_secs(self.pagination_ttl)
+    }
+
+    pub fn status_poll_interval(&self) -> Duration {
+        Duration::from_secs(self.status_poll_interval)
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NotificationsConfig {
+    /// Channel of each guild to post status changes in, unless another
+    /// channel is selected with the `notifications` command.
+    pub channels: HashMap<GuildId, ChannelId>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RankRolesConfig {
+    /// Seconds between updating the rank roles of every linked member.
+    pub sync_interval: u64,
+    /// Role given to the members in each rank tier, for each guild. Members
+    /// in a tier without a role have none of the roles.
+    pub guilds: HashMap<GuildId, HashMap<RankTier, RoleId>>,
+}
+
+impl Default for RankRolesConfig {
+    fn default() -> Self {
+        Self {
+            sync_interval: 6 * 60 * 60,
+            guilds: HashMap::default(),
+        }
+    }
+}
+
+impl RankRolesConfig {
+    pub fn sync_interval(&self) -> Duration {
+        Duration::from_secs(self.sync_interval)
+    }
+}
+
+impl serenity::prelude::TypeMapKey for RankRolesConfig {
+    type Value = RankRolesConfig;
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DigestConfig {
+    /// Post a digest of the linked players in the notification channel of
+    /// each guild.
+    pub enabled: bool,
+    /// Day of the week to post the digest on, e.g. `Sunday`.
+    #[serde(deserialize_with = "from_str")]
+    pub weekday: Weekday,
+    /// Hour of the day in UTC to post the digest at, from 0 to 23.
+    pub hour: u32,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            weekday: Weekday::Sun,
+            hour: 18,
+        }
+    }
+}
+
+impl DigestConfig {
+    /// The first time after `time` the digest should be posted.
+    pub fn next_after(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let days =
+            (self.weekday.num_days_from_monday() + 7 - time.weekday().num_days_from_monday()) % 7;
+        let next = (time.date_naive() + chrono::Duration::days(days as i64))
+            .and_hms_opt(self.hour, 0, 0)
+            .expect("the hour is validated");
+        let next = Utc.from_utc_datetime(&next);
+
+        match next > time {
+            true => next,
+            false => next + chrono::Duration::weeks(1),
+        }
+    }
+}
+
+impl Config {
+    /// Load the file given by `SIEGE_BOT_CONFIG`, or `siege-bot.toml` if it
+    /// exists, and apply the overrides from the environment.
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = std::env::var("SIEGE_BOT_CONFIG").ok();
+        let content = match path.as_deref() {
+            Some(path) => Some(read(Path::new(path))?),
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => {
+                Some(read(Path::new(DEFAULT_CONFIG_FILE))?)
+            }
+            None => None,
+        };
+
+        Self::parse(content.as_deref().unwrap_or_default(), |name| {
+            std::env::var(name).ok()
+        })
+    }
+
+    /// Parse the configuration, where `env` looks up environment variables.
+    pub fn parse(content: &str, env: impl Fn(&str) -> Option<String>) -> Result<Self, ConfigError> {
+        let mut table: toml::Table = content.parse()?;
+        ENV_OVERRIDES.iter().for_each(|(name, path)| {
+            if let Some(value) = env(name) {
+                set(&mut table, path, toml::Value::String(value));
+            }
+        });
+        NUMERIC_ENV_OVERRIDES.iter().for_each(|(name, path)| {
+            if let Some(value) = env(name) {
+                let value = match value.parse() {
+                    Ok(number) => toml::Value::Integer(number),
+                    Err(_) => toml::Value::String(value),
+                };
+                set(&mut table, path, value);
+            }
+        });
+
+        let config = Config::deserialize(table)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        [
+            (&self.discord.token, "discord.token", "DISCORD_TOKEN"),
+            (&self.ubisoft.email, "ubisoft.email", "UBISOFT_EMAIL"),
+            (
+                &self.ubisoft.password,
+                "ubisoft.password",
+                "UBISOFT_PASSWORD",
+            ),
+        ]
+        .into_iter()
+        .try_for_each(|(value, name, variable)| match value.trim().is_empty() {
+            true => Err(ConfigError::Missing(name, variable)),
+            false => Ok(()),
+        })?;
+
+        if self.defaults.platform == PlatformFamily::Unknown {
+            return Err(ConfigError::Invalid(
+                "defaults.platform",
+                "expected `pc` or `console`".to_string(),
+            ));
+        }
+        if self.defaults.statistics_game_mode == GameMode::Unknown {
+            return Err(ConfigError::Invalid(
+                "defaults.statistics_game_mode",
+                "expected a known game mode".to_string(),
+            ));
+        }
+        if self.cache.pagination_ttl == 0 {
+            return Err(ConfigError::Invalid(
+                "cache.pagination_ttl",
+                "must be at least one second".to_string(),
+            ));
+        }
+        if self.cache.status_poll_interval == 0 {
+            return Err(ConfigError::Invalid(
+                "cache.status_poll_interval",
+                "must be at least one second".to_string(),
+            ));
+        }
+        if self.digest.hour > 23 {
+            return Err(ConfigError::Invalid(
+                "digest.hour",
+                "must be from 0 to 23".to_string(),
+            ));
+        }
+        if self.rank_roles.sync_interval == 0 {
+            return Err(ConfigError::Invalid(
+                "rank_roles.sync_interval",
+                "must be at least one second".to_string(),
+            ));
+        }
+        if self.commands.registration == Registration::Global && !self.commands.guilds.is_empty() {
+            return Err(ConfigError::Invalid(
+                "commands.guilds",
+                "only used with `registration = \"guild\"`".to_string(),
+            ));
+        }
+        self.logging.filter_directives()?;
+
+        Ok(())
+    }
+}
+
+fn read(path: &Path) -> Result<String, ConfigError> {
+    std::fs::read_to_string(path).map_err(|err| ConfigError::Read(path.to_path_buf(), err))
+}
+
+/// Set the value at the path in the table, creating the tables on the way.
+fn set(table: &mut toml::Table, path: &[&str], value: toml::Value) {
+    let (key, tables) = path.split_last().expect("path is never empty");
+    let table = tables.iter().fold(table, |table, name| {
+        match table
+            .entry(name.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+        {
+            toml::Value::Table(table) => table,
+            value => {
+                *value = toml::Value::Table(toml::Table::new());
+                value.as_table_mut().expect("just inserted a table")
+            }
+        }
+    });
+    table.insert(key.to_string(), value);
+}
+
+/// Deserialize a value from a string with its `FromStr` implementation.
+fn from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let value = String::deserialize(deserializer)?;
+    value
+        .parse()
+        .map_err(|err| serde::de::Error::custom(format!("`{value}`: {err}")))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn secrets(name: &str) -> Option<String> {
+        match name {
+            "DISCORD_TOKEN" => Some("token".to_string()),
+            "UBISOFT_EMAIL" => Some("mail@example.com".to_string()),
+            // Secrets are never parsed as numbers.
+            "UBISOFT_PASSWORD" => Some("1234".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn defaults_with_secrets_from_environment() {
+        let config = Config::parse("", secrets).unwrap();
+
+        assert_eq!(config.discord.token, "token");
+        assert_eq!(config.ubisoft.email, "mail@example.com");
+        assert_eq!(config.storage.backend, StorageBackend::Json);
+        assert_eq!(
+            config.storage.file(".players.json"),
+            PathBuf::from("./.players.json")
+        );
+        assert_eq!(config.logging.level, LevelFilter::DEBUG);
+        assert_eq!(config.commands.enabled, None);
+        assert_eq!(config.defaults, Defaults::default());
+        assert_eq!(config.cache.pagination_ttl(), Duration::from_secs(600));
+        assert!(config.notifications.channels.is_empty());
+    }
+
+    #[test]
+    fn parse_file() {
+        let content = r#"
+            [discord]
+            token = "file token"
+
+            [storage]
+            directory = "/config"
+
+            [logging]
+            level = "info"
+            directives = ["serenity=warn"]
+
+            [commands]
+            enabled = ["ping", "statistics"]
+
+            [defaults]
+            platform = "Console"
+            game_mode = "Ranked"
+            statistics_game_mode = "Ranked"
+            minimum_rounds = 10
+            ephemeral = true
+
+            [cache]
+            pagination_ttl = 60
+
+            [notifications.channels]
+            "1234" = 5678
+
+            [rank_roles.guilds."1234"]
+            Gold = "42"
+            Diamond = 43
+        "#;
+        let config = Config::parse(content, secrets).unwrap();
+
+        // The environment takes precedence over the file.
+        assert_eq!(config.discord.token, "token");
+        assert_eq!(config.storage.directory, PathBuf::from("/config"));
+        assert_eq!(config.logging.level, LevelFilter::INFO);
+        assert_eq!(config.logging.filter_directives().unwrap().len(), 2);
+        assert_eq!(
+            config.commands.enabled.unwrap(),
+            vec!["ping".to_string(), "statistics".to_string()]
+        );
+        assert_eq!(config.defaults.platform, PlatformFamily::Console);
+        assert_eq!(config.defaults.game_mode, GameModeOrAll::Ranked);
+        assert_eq!(config.defaults.statistics_game_mode, GameMode::Ranked);
+        assert_eq!(config.defaults.minimum_rounds, 10);
+        assert!(config.defaults.ephemeral);
+        assert_eq!(config.cache.pagination_ttl(), Duration::from_secs(60));
+        assert_eq!(
+            config.notifications.channels.get(&GuildId(1234)),
+            Some(&ChannelId(5678))
+        );
+        let roles = config.rank_roles.guilds.get(&GuildId(1234)).unwrap();
+        assert_eq!(roles.get(&RankTier::Gold), Some(&RoleId(42)));
+        assert_eq!(roles.get(&RankTier::Diamond), Some(&RoleId(43)));
+    }
+
+    #[test]
+    fn example_has_the_defaults() {
+        let example = include_str!("../../siege-bot.example.toml");
+        let config = Config::parse(example, secrets).unwrap();
+        let defaults = Config::parse("", secrets).unwrap();
+
+        assert_eq!(format!("{config:?}"), format!("{defaults:?}"));
+    }
+
+    #[test]
+    fn override_from_environment() {
+        let content = r#"
+            [cache]
+            pagination_ttl = 60
+        "#;
+        let config = Config::parse(content, |name| match name {
+            "PAGINATION_TTL" => Some("120".to_string()),
+            "DEFAULT_GAME_MODE" => Some("Casual".to_string()),
+            "DIGEST_WEEKDAY" => Some("monday".to_string()),
+            "DIGEST_HOUR" => Some("9".to_string()),
+            "LOG_LEVEL" => Some("warn".to_string()),
+            name => secrets(name),
+        })
+        .unwrap();
+
+        assert_eq!(config.cache.pagination_ttl(), Duration::from_secs(120));
+        assert_eq!(config.defaults.game_mode, GameModeOrAll::Casual);
+        assert_eq!(config.digest.weekday, Weekday::Mon);
+        assert_eq!(config.digest.hour, 9);
+        assert_eq!(config.logging.level, LevelFilter::WARN);
+    }
+
+    #[test]
+    fn next_digest() {
+        let digest = DigestConfig::default();
+        // Sunday the 2nd of April 2023 at 18:00.
+        let sunday = Utc.with_ymd_and_hms(2023, 4, 2, 18, 0, 0).unwrap();
+
+        assert_eq!(
+            digest.next_after(Utc.with_ymd_and_hms(2023, 3, 29, 12, 30, 0).unwrap()),
+            sunday
+        );
+        assert_eq!(
+            digest.next_after(sunday - chrono::Duration::seconds(1)),
+            sunday
+        );
+        assert_eq!(
+            digest.next_after(sunday),
+            sunday + chrono::Duration::weeks(1)
+        );
+    }
+
+    #[test]
+    fn command_registration() {
+        let config = Config::parse("", secrets).unwrap();
+        assert_eq!(config.commands.registration, Registration::Guild);
+        assert!(config.commands.registers_in_guild(GuildId(1)));
+
+        let config = Config::parse("[commands]\nguilds = [\"1\", \"2\"]", secrets).unwrap();
+        assert!(config.commands.registers_in_guild(GuildId(2)));
+        assert!(!config.commands.registers_in_guild(GuildId(3)));
+
+        let config = Config::parse("", |name| match name {
+            "COMMAND_REGISTRATION" => Some("global".to_string()),
+            name => secrets(name),
+        })
+        .unwrap();
+        assert_eq!(config.commands.registration, Registration::Global);
+        assert!(!config.commands.registers_in_guild(GuildId(1)));
+
+        let content = "[commands]\nregistration = \"global\"\nguilds = [\"1\"]";
+        assert!(matches!(
+            Config::parse(content, secrets).unwrap_err(),
+            ConfigError::Invalid("commands.guilds", _)
+        ));
+    }
+
+    #[test]
+    fn missing_secrets() {
+        let error = Config::parse("", |_| None).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "`discord.token` must be set, either in the configuration file or with `DISCORD_TOKEN`"
+        );
+
+        let error = Config::parse("", |name| match name {
+            "UBISOFT_PASSWORD" => Some(" ".to_string()),
+            "UBISOFT_EMAIL" => None,
+            name => secrets(name),
+        })
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            ConfigError::Missing("ubisoft.email", "UBISOFT_EMAIL")
+        ));
+    }
+
+    #[test]
+    fn invalid_values() {
+        let invalid = [
+            "[defaults]\ngame_mode = \"Arcade\"",
+            "[defaults]\nplatform = \"Switch\"",
+            "[defaults]\nstatistics_game_mode = \"Unknown\"",
+            "[defaults]\nminimum_rounds = -1",
+            "[logging]\nlevel = \"loud\"",
+            "[logging]\ndirectives = [\"siege_api=[\"]",
+            "[storage]\nbackend = \"postgres\"",
+            "[commands]\nregistration = \"everywhere\"",
+            "[cache]\npagination_ttl = 0",
+            "[cache]\nstatus_poll_interval = -5",
+            "[rank_roles]\nsync_interval = 0",
+            "[digest]\nweekday = \"someday\"",
+            "[digest]\nhour = 24",
+            "[rank_roles.guilds.\"1\"]\nGolden = 2",
+            "[discord]\ntokne = \"typo\"",
+            "not toml",
+        ];
+
+        invalid.into_iter().for_each(|content| {
+            assert!(
+                Config::parse(content, secrets).is_err(),
+                "expected an error for {content}"
+            );
+        });
+    }
+
+    #[test]
+    fn read_stored_file_from_legacy_directory() {
+        let storage = tempfile::tempdir().unwrap();
+        let legacy = tempfile::tempdir().unwrap();
+        let path = storage.path().join(".players.json");
+
+        assert!(read_with_legacy_fallback(&path, legacy.path()).is_err());
+
+        std::fs::write(legacy.path().join(".players.json"), "legacy").unwrap();
+        assert_eq!(
+            read_with_legacy_fallback(&path, legacy.path()).unwrap(),
+            "legacy"
+        );
+
+        // Once written to the storage directory, the legacy file is ignored.
+        std::fs::write(&path, "current").unwrap();
+        assert_eq!(
+            read_with_legacy_fallback(&path, legacy.path()).unwrap(),
+            "current"
+        );
+    }
+
+    #[test]
+    fn read_missing_file() {
+        let error = read(Path::new("does not exist.toml")).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("failed to read `does not exist.toml`"));
+    }
+}
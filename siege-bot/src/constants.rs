@@ -5,5 +5,25 @@ pub const GAME_MODE: &str = "game_mode";
 pub const SIDE: &str = "side";
 pub const SORTING: &str = "sorting";
 pub const MINIMUM_ROUNDS: &str = "minimum_rounds";
+pub const ENABLED: &str = "enabled";
+pub const STATISTICS_GAME_MODE: &str = "statistics_game_mode";
+pub const ANNOUNCEMENT_CHANNEL: &str = "announcement_channel";
+pub const EPHEMERAL: &str = "ephemeral";
+pub const RESET: &str = "reset";
+pub const STATISTICS: &str = "statistics";
+pub const ROLE: &str = "role";
+pub const SECOND_ROLE: &str = "second_role";
+pub const MATCH_ALL_ROLES: &str = "match_all_roles";
+pub const SPEED: &str = "speed";
+pub const UNIT: &str = "unit";
+pub const COUNTRY: &str = "country";
+pub const SEASON: &str = "season";
+pub const PLAYED_ONLY: &str = "played_only";
+pub const ROLE_COVERAGE: &str = "role_coverage";
+pub const START: &str = "start";
+pub const END: &str = "end";
+pub const PLAYERS: [&str; TEAM_SIZE] = ["player1", "player2", "player3", "player4", "player5"];
 
 pub const AUTOCOMPLETE_LIMIT: usize = 25;
+/// Number of players in a team.
+pub const TEAM_SIZE: usize = 5;
@@ -0,0 +1,489 @@
+//! A digest of the linked players of each guild, posted on a schedule in the
+//! notification channel of the guild.
+
+use std::{
+    collections::HashMap,
+    fs::{read_to_string, write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use chrono::Utc;
+use serenity::{
+    builder::CreateEmbed,
+    http::Http,
+    model::prelude::GuildId,
+    prelude::{RwLock, TypeMap},
+};
+use siege_api::{
+    data::rank::Rank,
+    models::{
+        meta::{GameStatus, SIEGE_TITLE},
+        GameMode, RankedV2Response, SideOrAll, StatisticResponse,
+    },
+};
+use uuid::Uuid;
+
+use crate::{
+    config::{Defaults, DigestConfig},
+    formatting::FormatEmbedded,
+    guild_settings::guild_defaults,
+    notification_channels::NotificationChannels,
+    siege_player_lookup::SiegePlayerLookup,
+    SiegeApi,
+};
+
+/// Number of players, operators and maps listed in each section.
+const LISTED: usize = 5;
+/// Players need to have played this many matches to be a top performer.
+const MINIMUM_MATCHES: u64 = 5;
+
+/// The statistics of a linked member shown in the digest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemberSummary {
+    pub name: String,
+    pub player_id: Uuid,
+    /// K/D, win rate and matches of the current season.
+    pub kd: Option<f64>,
+    pub win_rate: Option<f64>,
+    pub matches: u64,
+    pub rank: Rank,
+    /// `None` if the player has no ranked profile on the platform.
+    pub rank_points: Option<u64>,
+    /// Rounds played on each operator and map.
+    pub operators: Vec<(String, u64)>,
+    pub maps: Vec<(String, u64)>,
+}
+
+impl MemberSummary {
+    pub fn new(
+        name: String,
+        player_id: Uuid,
+        profiles: &RankedV2Response,
+        operators: &StatisticResponse,
+        maps: &StatisticResponse,
+        defaults: Defaults,
+    ) -> Self {
+        let season = profiles
+            .get_board(defaults.platform, defaults.statistics_game_mode)
+            .map(|board| *board.season_statistics());
+        let ranked = profiles
+            .get_board(defaults.platform, GameMode::Ranked)
+            .map(|board| *board.profile());
+
+        Self {
+            name,
+            player_id,
+            kd: season.and_then(|x| x.kd()),
+            win_rate: season.and_then(|x| x.match_outcomes().win_rate()),
+            matches: season.map_or(0, |x| x.match_outcomes().total_matches()),
+            rank: ranked
+                .and_then(|x| Rank::from_repr(*x.rank()))
+                .unwrap_or(Rank::Unranked),
+            rank_points: ranked.map(|x| *x.rank_points()),
+            operators: operators
+                .get_operators(defaults.game_mode, SideOrAll::All)
+                .iter()
+                .map(|x| (x.name().to_string(), *x.statistics().rounds_played()))
+                .collect(),
+            maps: maps
+                .get_maps(defaults.game_mode, SideOrAll::All)
+                .iter()
+                .map(|x| (x.name().to_string(), *x.statistics().rounds_played()))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Digest {
+    pub members: Vec<MemberSummary>,
+    /// Rank points of the players when the previous digest was posted, if any.
+    pub previous_rank_points: Option<HashMap<Uuid, u64>>,
+    /// Status of the game instances, if it could be fetched.
+    pub status: Option<Vec<GameStatus>>,
+}
+
+impl Digest {
+    /// The members with the highest K/D, out of those who have played enough
+    /// matches.
+    pub fn top_performers(&self) -> Vec<&MemberSummary> {
+        let mut members = self
+            .members
+            .iter()
+            .filter(|x| x.matches >= MINIMUM_MATCHES && x.kd.is_some())
+            .collect::<Vec<_>>();
+        members.sort_by(|a, b| {
+            b.kd.unwrap_or_default()
+                .total_cmp(&a.kd.unwrap_or_default())
+        });
+        members.truncate(LISTED);
+        members
+    }
+
+    /// The members who have gained the most rank points since the previous
+    /// digest.
+    pub fn rank_point_gains(&self) -> Vec<(&MemberSummary, i64)> {
+        let previous = match &self.previous_rank_points {
+            Some(previous) => previous,
+            None => return Vec::new(),
+        };
+
+        let mut gains = self
+            .members
+            .iter()
+            .filter_map(|member| {
+                let before = previous.get(&member.player_id)?;
+                let now = member.rank_points?;
+                Some((member, now as i64 - *before as i64))
+            })
+            .filter(|(_, gain)| *gain != 0)
+            .collect::<Vec<_>>();
+        gains.sort_by_key(|(_, gain)| std::cmp::Reverse(*gain));
+        gains.truncate(LISTED);
+        gains
+    }
+
+    /// The operators with the most rounds played by all the members.
+    pub fn most_played_operators(&self) -> Vec<(String, u64)> {
+        most_played(self.members.iter().flat_map(|x| &x.operators))
+    }
+
+    /// The maps with the most rounds played by all the members.
+    pub fn most_played_maps(&self) -> Vec<(String, u64)> {
+        most_played(self.members.iter().flat_map(|x| &x.maps))
+    }
+
+    /// Rank points of the members, to compare the next digest with.
+    pub fn rank_points(&self) -> HashMap<Uuid, u64> {
+        self.members
+            .iter()
+            .filter_map(|x| x.rank_points.map(|points| (x.player_id, points)))
+            .collect()
+    }
+}
+
+fn most_played<'a>(rounds: impl Iterator<Item = &'a (String, u64)>) -> Vec<(String, u64)> {
+    let mut totals = HashMap::<&str, u64>::new();
+    rounds.for_each(|(name, rounds)| *totals.entry(name).or_default() += rounds);
+
+    let mut totals = totals
+        .into_iter()
+        .filter(|(_, rounds)| *rounds > 0)
+        .map(|(name, rounds)| (name.to_string(), rounds))
+        .collect::<Vec<_>>();
+    totals.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    totals.truncate(LISTED);
+    totals
+}
+
+/// Keeps the rank points of the players in each guild when the last digest was
+/// posted, to show the gains in the next one.
+#[derive(Debug)]
+pub struct RankPointSnapshots {
+    path: PathBuf,
+    guilds: HashMap<GuildId, HashMap<Uuid, u64>>,
+}
+
+impl RankPointSnapshots {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let guilds = match read_to_string(path) {
+            Ok(content) => serde_json::from_str(content.as_str())?,
+            Err(err) => {
+                tracing::warn!("Failed to read digest snapshots. Creating default. Error: {err:?}");
+                HashMap::default()
+            }
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            guilds,
+        })
+    }
+
+    pub fn get(&self, guild_id: &GuildId) -> Option<&HashMap<Uuid, u64>> {
+        self.guilds.get(guild_id)
+    }
+
+    pub fn insert(
+        &mut self,
+        guild_id: &GuildId,
+        rank_points: HashMap<Uuid, u64>,
+    ) -> Result<(), std::io::Error> {
+        self.guilds.insert(*guild_id, rank_points);
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), std::io::Error> {
+        let content =
+            serde_json::to_string_pretty(&self.guilds).expect("should always be serializeable");
+        write(&self.path, content)
+    }
+}
+
+/// The responses from the Siege API the summary of a player is made from.
+struct PlayerData {
+    profiles: RankedV2Response,
+    operators: StatisticResponse,
+    maps: StatisticResponse,
+}
+
+/// Post the digest on the schedule in the background, if enabled.
+pub async fn watch(
+    http: Arc<Http>,
+    data: Arc<RwLock<TypeMap>>,
+    config: DigestConfig,
+    mut snapshots: RankPointSnapshots,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    loop {
+        let now = Utc::now();
+        let next = config.next_after(now);
+        tracing::debug!("Posting the next digest at {next}");
+        tokio::time::sleep((next - now).to_std().unwrap_or_default()).await;
+
+        post(&http, &data, &mut snapshots).await;
+    }
+}
+
+async fn post(http: &Http, data: &RwLock<TypeMap>, snapshots: &mut RankPointSnapshots) {
+    let (players, siege_client, channels) = {
+        let data = data.read().await;
+        let players = data
+            .get::<SiegePlayerLookup>()
+            .expect("always registered")
+            .read()
+            .await
+            .all();
+        let siege_client = data
+            .get::<SiegeApi>()
+            .expect("client always registered")
+            .clone();
+        let mut channels = Vec::new();
+        for (guild_id, channel_id) in data
+            .get::<NotificationChannels>()
+            .expect("always registered")
+            .read()
+            .await
+            .all()
+        {
+            let defaults = guild_defaults(&data, Some(guild_id)).await;
+            channels.push((guild_id, channel_id, defaults));
+        }
+
+        (players, siege_client, channels)
+    };
+
+    let status = match siege_client
+        .siege_status(Some(SIEGE_TITLE.to_string()))
+        .await
+    {
+        Ok(status) => Some(status),
+        Err(err) => {
+            tracing::warn!("Failed to fetch the game status for the digest: {err:?}");
+            None
+        }
+    };
+
+    // Members of several guilds only have their statistics fetched once.
+    let mut player_data: HashMap<Uuid, PlayerData> = HashMap::new();
+
+    for (guild_id, channel_id, defaults) in channels {
+        tracing::info!("Posting the digest in {guild_id}/{channel_id}");
+
+        let mut members = Vec::new();
+        for (user_id, player_id) in &players {
+            // Most linked players are usually not in every guild.
+            let name = match guild_id.member(http, user_id).await {
+                Ok(member) => member.display_name().to_string(),
+                Err(_) => continue,
+            };
+
+            if !player_data.contains_key(player_id) {
+                let fetched = async {
+                    Ok::<_, siege_api::auth::ConnectError>(PlayerData {
+                        profiles: siege_client.get_full_profiles(*player_id).await?,
+                        operators: siege_client.get_operators(*player_id).await?,
+                        maps: siege_client.get_maps(*player_id).await?,
+                    })
+                };
+                match fetched.await {
+                    Ok(fetched) => {
+                        player_data.insert(*player_id, fetched);
+                    }
+                    Err(err) => {
+                        tracing::warn!("Failed to fetch the statistics of {player_id}: {err:?}");
+                        continue;
+                    }
+                }
+            }
+
+            let fetched = &player_data[player_id];
+            members.push(MemberSummary::new(
+                name,
+                *player_id,
+                &fetched.profiles,
+                &fetched.operators,
+                &fetched.maps,
+                defaults,
+            ));
+        }
+
+        let digest = Digest {
+            members,
+            previous_rank_points: snapshots.get(&guild_id).cloned(),
+            status: status.clone(),
+        };
+        let mut embed = CreateEmbed::default();
+        embed.format(&digest);
+
+        match channel_id
+            .send_message(http, |message| message.set_embed(embed))
+            .await
+        {
+            Ok(_) => {
+                if let Err(err) = snapshots.insert(&guild_id, digest.rank_points()) {
+                    tracing::error!("Failed to store the rank points of {guild_id}: {err:?}");
+                }
+            }
+            Err(err) => {
+                tracing::error!("Failed to post the digest in {guild_id}/{channel_id}: {err:?}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use tempfile::NamedTempFile;
+
+    use super::*;
+
+    pub fn member(name: &str, kd: f64, matches: u64, rank_points: Option<u64>) -> MemberSummary {
+        MemberSummary {
+            name: name.to_string(),
+            player_id: Uuid::new_v4(),
+            kd: Some(kd),
+            win_rate: Some(0.5),
+            matches,
+            rank: Rank::GoldIII,
+            rank_points,
+            operators: vec![("Ash".to_string(), 10), ("Thermite".to_string(), 5)],
+            maps: vec![("Bank".to_string(), 20)],
+        }
+    }
+
+    #[test]
+    fn member_summary_from_samples() {
+        let read = |filename: &str| std::fs::read_to_string(filename).unwrap();
+        let profiles = serde_json::from_str(&read("../samples/full_profile.json")).unwrap();
+        let operators = serde_json::from_str(&read("../samples/operators.json")).unwrap();
+        let maps = serde_json::from_str(&read("../samples/maps.json")).unwrap();
+
+        let summary = MemberSummary::new(
+            "name".to_string(),
+            Uuid::new_v4(),
+            &profiles,
+            &operators,
+            &maps,
+            Defaults::default(),
+        );
+
+        assert_eq!(summary.rank, Rank::CopperII);
+        assert_eq!(summary.rank_points, Some(1300));
+        assert!(!summary.operators.is_empty());
+        assert!(!summary.maps.is_empty());
+    }
+
+    #[test]
+    fn top_performers() {
+        let mut digest = Digest {
+            members: vec![
+                member("low", 0.8, 10, None),
+                member("few matches", 3.0, 2, None),
+                member("high", 1.4, 10, None),
+            ],
+            previous_rank_points: None,
+            status: None,
+        };
+
+        let names = digest
+            .top_performers()
+            .iter()
+            .map(|x| x.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["high", "low"]);
+
+        // A K/D which is not a number does not break the sorting.
+        digest
+            .members
+            .push(member("not a number", f64::NAN, 10, None));
+        assert_eq!(digest.top_performers().len(), 3);
+    }
+
+    #[test]
+    fn rank_point_gains() {
+        let members = vec![
+            member("lost", 1.0, 10, Some(2400)),
+            member("gained", 1.0, 10, Some(2600)),
+            member("new", 1.0, 10, Some(3000)),
+            member("unranked", 1.0, 10, None),
+        ];
+        let previous = HashMap::from([
+            (members[0].player_id, 2500),
+            (members[1].player_id, 2500),
+            (members[3].player_id, 2500),
+        ]);
+        let mut digest = Digest {
+            members,
+            previous_rank_points: None,
+            status: None,
+        };
+        assert!(digest.rank_point_gains().is_empty());
+
+        digest.previous_rank_points = Some(previous);
+        let gains = digest
+            .rank_point_gains()
+            .iter()
+            .map(|(x, gain)| (x.name.as_str(), *gain))
+            .collect::<Vec<_>>();
+        assert_eq!(gains, vec![("gained", 100), ("lost", -100)]);
+        assert_eq!(digest.rank_points().len(), 3);
+    }
+
+    #[test]
+    fn most_played() {
+        let digest = Digest {
+            members: vec![member("a", 1.0, 10, None), member("b", 1.0, 10, None)],
+            previous_rank_points: None,
+            status: None,
+        };
+
+        assert_eq!(
+            digest.most_played_operators(),
+            vec![("Ash".to_string(), 20), ("Thermite".to_string(), 10)]
+        );
+        assert_eq!(digest.most_played_maps(), vec![("Bank".to_string(), 40)]);
+    }
+
+    #[test]
+    fn snapshots_insert_and_load() {
+        let guild_id = GuildId(1);
+        let rank_points = HashMap::from([(Uuid::new_v4(), 2500)]);
+
+        let file = NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "{}").unwrap();
+        let mut snapshots = RankPointSnapshots::load(file.path()).unwrap();
+        assert_eq!(snapshots.get(&guild_id), None);
+        snapshots
+            .insert(&guild_id, rank_points.clone())
+            .expect("should be able to persist");
+
+        let snapshots = RankPointSnapshots::load(file.path()).unwrap();
+        assert_eq!(snapshots.get(&guild_id), Some(&rank_points));
+    }
+}
@@ -1,7 +1,44 @@
 mod all_maps_format;
 mod all_operators_format;
+mod digest_format;
+mod game_status_format;
+mod loadout_format;
+mod map_bans_format;
+mod map_details_format;
+mod operator_details_format;
+mod session_format;
+mod side_breakdown_format;
 mod statistics_format;
+mod team_format;
+
+/// Shown in place of ratios which are undefined, e.g. a win rate without any
+/// matches played.
+pub(crate) const UNDEFINED: &str = "—";
+
+/// Format a ratio between 0 and 1 as a percentage, e.g. `54.55 %`.
+pub(crate) fn percentage(value: Option<f64>) -> String {
+    value.map_or_else(|| UNDEFINED.to_string(), |x| format!("{:.2} %", 100.0 * x))
+}
+
+/// Format a ratio with two decimals, e.g. a K/D of `1.24`.
+pub(crate) fn decimal(value: Option<f64>) -> String {
+    value.map_or_else(|| UNDEFINED.to_string(), |x| format!("{x:.2}"))
+}
 
 pub trait FormatEmbedded<'a, T> {
     fn format(&'a mut self, value: &T) -> &'a mut Self;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn format_ratios() {
+        assert_eq!(percentage(Some(0.5454545)), "54.55 %");
+        assert_eq!(percentage(Some(0.0)), "0.00 %");
+        assert_eq!(percentage(None), "—");
+        assert_eq!(decimal(Some(1.236)), "1.24");
+        assert_eq!(decimal(None), "—");
+    }
+}
@@ -1,7 +1,7 @@
 use serenity::{builder::CreateEmbed, model::Timestamp};
 use siege_api::models::MapStatistics;
 
-use super::FormatEmbedded;
+use super::{percentage, FormatEmbedded};
 
 impl FormatEmbedded<'_, Vec<&MapStatistics>> for CreateEmbed {
     fn format(&mut self, maps: &Vec<&MapStatistics>) -> &mut Self {
@@ -20,10 +20,10 @@ impl FormatEmbedded<'_, Vec<&MapStatistics>> for CreateEmbed {
             .iter()
             .map(|x| {
                 format!(
-                    "M: `{:.2} %` (`{}`) R: `{:.2} %` (`{: >3}`)",
-                    100.0 * x.statistics().matches_win_rate(),
+                    "M: `{}` (`{}`) R: `{}` (`{: >3}`)",
+                    percentage(x.statistics().matches_win_rate()),
                     x.statistics().matches_played(),
-                    100.0 * x.statistics().rounds_win_rate(),
+                    percentage(x.statistics().rounds_win_rate()),
                     x.statistics().rounds_played()
                 )
             })
@@ -42,7 +42,7 @@ mod test {
     use std::ops::Sub;
 
     use chrono::{DateTime, Utc};
-    use siege_api::models::{AllOrRanked, SideOrAll, StatisticResponse};
+    use siege_api::models::{GameModeOrAll, SideOrAll, StatisticResponse};
 
     use super::*;
 
@@ -51,7 +51,7 @@ mod test {
         let mut embed = CreateEmbed::default();
         let content = std::fs::read_to_string("../samples/maps.json").unwrap();
         let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
-        let maps = stats.get_maps(AllOrRanked::All, SideOrAll::All);
+        let maps = stats.get_maps(GameModeOrAll::All, SideOrAll::All);
 
         embed.format(&maps);
 
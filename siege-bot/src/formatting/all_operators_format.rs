@@ -1,7 +1,7 @@
 use serenity::{builder::CreateEmbed, model::Timestamp};
 use siege_api::models::OperatorStatistics;
 
-use super::FormatEmbedded;
+use super::{percentage, FormatEmbedded};
 
 impl FormatEmbedded<'_, Vec<&OperatorStatistics>> for CreateEmbed {
     fn format(&mut self, operators: &Vec<&OperatorStatistics>) -> &mut Self {
@@ -20,8 +20,8 @@ impl FormatEmbedded<'_, Vec<&OperatorStatistics>> for CreateEmbed {
             .iter()
             .map(|op| {
                 format!(
-                    "`{: >6.2} %` (of `{: >3}`)",
-                    100.0 * op.statistics().rounds_win_rate(),
+                    "`{: >8}` (of `{: >3}`)",
+                    percentage(op.statistics().rounds_win_rate()),
                     op.statistics().rounds_played()
                 )
             })
@@ -40,7 +40,7 @@ mod test {
     use std::ops::Sub;
 
     use chrono::{DateTime, Utc};
-    use siege_api::models::{AllOrRanked, SideOrAll, StatisticResponse};
+    use siege_api::models::{GameModeOrAll, SideOrAll, StatisticResponse};
 
     use super::*;
 
@@ -49,7 +49,7 @@ mod test {
         let mut embed = CreateEmbed::default();
         let content = std::fs::read_to_string("../samples/operators.json").unwrap();
         let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
-        let maps = stats.get_operators(AllOrRanked::All, SideOrAll::All);
+        let maps = stats.get_operators(GameModeOrAll::All, SideOrAll::All);
 
         embed.format(&maps);
 
@@ -0,0 +1,174 @@
+use serenity::{builder::CreateEmbed, model::Timestamp, utils::Color};
+use siege_api::models::meta::Status;
+
+use crate::digest::Digest;
+
+use super::{decimal, game_status_format::status_emoji, percentage, FormatEmbedded};
+
+/// Join the lines of a field, which Discord requires to have a value.
+fn lines_or(lines: Vec<String>, empty: &str) -> String {
+    match lines.is_empty() {
+        true => empty.to_string(),
+        false => lines.join("\n"),
+    }
+}
+
+/// Create an embedded Discord message with the top performers, rank point
+/// gains, most played operators and maps of the linked members, and the
+/// status of the game.
+impl FormatEmbedded<'_, Digest> for CreateEmbed {
+    fn format(&mut self, digest: &Digest) -> &mut Self {
+        let performers = digest
+            .top_performers()
+            .iter()
+            .map(|member| {
+                format!(
+                    "**{}** K/D **{}** - Win rate **{}** - {} matches",
+                    member.name,
+                    decimal(member.kd),
+                    percentage(member.win_rate),
+                    member.matches,
+                )
+            })
+            .collect();
+
+        let gains = match digest.previous_rank_points {
+            Some(_) => lines_or(
+                digest
+                    .rank_point_gains()
+                    .iter()
+                    .map(|(member, gain)| {
+                        format!("**{}** {gain:+} RP - {}", member.name, member.rank)
+                    })
+                    .collect(),
+                "No changes since the last digest",
+            ),
+            None => "Shown from the next digest".to_string(),
+        };
+
+        let rounds = |played: Vec<(String, u64)>| {
+            lines_or(
+                played
+                    .iter()
+                    .map(|(name, rounds)| format!("**{name}** {rounds} rounds"))
+                    .collect(),
+                "Nothing played yet",
+            )
+        };
+
+        let status = match &digest.status {
+            Some(status) => lines_or(
+                status
+                    .iter()
+                    .map(|instance| {
+                        format!(
+                            "{} {}: **{}**",
+                            status_emoji(instance.status()),
+                            instance.platform(),
+                            instance.status()
+                        )
+                    })
+                    .collect(),
+                "No instances reported",
+            ),
+            None => "Failed to fetch the status".to_string(),
+        };
+        let all_online = digest
+            .status
+            .as_ref()
+            .map_or(false, |x| x.iter().all(|x| *x.status() == Status::Online));
+
+        self.title("Digest")
+            .description(format!(
+                "{} linked players in this server",
+                digest.members.len()
+            ))
+            .timestamp(Timestamp::now())
+            .color(if all_online {
+                Color::TEAL
+            } else {
+                Color::ORANGE
+            })
+            .field(
+                "Top performers this season",
+                lines_or(performers, "Nobody has played enough matches"),
+                false,
+            )
+            .field("Rank point gains", gains, false)
+            .field(
+                "Most played operators",
+                rounds(digest.most_played_operators()),
+                true,
+            )
+            .field("Most played maps", rounds(digest.most_played_maps()), true)
+            .field("Server status", status, false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::digest::test::member;
+
+    use super::*;
+
+    fn field(embed: &CreateEmbed, index: usize) -> String {
+        embed.0.get("fields").unwrap().as_array().unwrap()[index]
+            .get("value")
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn format_validate() {
+        let members = vec![
+            member("first", 1.5, 10, Some(2600)),
+            member("second", 1.1, 10, Some(2400)),
+        ];
+        let previous = HashMap::from([(members[0].player_id, 2500)]);
+        let digest = Digest {
+            members,
+            previous_rank_points: Some(previous),
+            status: None,
+        };
+
+        let mut embed = CreateEmbed::default();
+        embed.format(&digest);
+
+        assert_eq!(embed.0.get("title").unwrap(), "Digest");
+        assert_eq!(
+            field(&embed, 0),
+            "**first** K/D **1.50** - Win rate **50.00 %** - 10 matches\n**second** K/D **1.10** - Win rate **50.00 %** - 10 matches"
+        );
+        assert_eq!(field(&embed, 1), "**first** +100 RP - GoldIII");
+        assert_eq!(
+            field(&embed, 2),
+            "**Ash** 20 rounds\n**Thermite** 10 rounds"
+        );
+        assert_eq!(field(&embed, 3), "**Bank** 40 rounds");
+        assert_eq!(field(&embed, 4), "Failed to fetch the status");
+    }
+
+    #[test]
+    fn format_without_members() {
+        let status =
+            serde_json::from_str(&std::fs::read_to_string("../samples/game-status.json").unwrap())
+                .unwrap();
+        let digest = Digest {
+            members: Vec::new(),
+            previous_rank_points: None,
+            status: Some(status),
+        };
+
+        let mut embed = CreateEmbed::default();
+        embed.format(&digest);
+
+        assert_eq!(field(&embed, 0), "Nobody has played enough matches");
+        assert_eq!(field(&embed, 1), "Shown from the next digest");
+        assert_eq!(field(&embed, 2), "Nothing played yet");
+        assert!(field(&embed, 4).contains("**Online**"));
+    }
+}
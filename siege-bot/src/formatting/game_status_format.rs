@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use serenity::{builder::CreateEmbed, model::Timestamp};
+use siege_api::models::meta::{GameStatus, Status};
+
+use super::FormatEmbedded;
+
+/// Get an emoji representing a status.
+pub fn status_emoji(status: &Status) -> &'static str {
+    match status {
+        Status::Online => "🟢",
+        Status::Degraded => "🟡",
+        Status::Interrupted => "🔴",
+        Status::Unknown(_) => "⚪",
+    }
+}
+
+/// Create an embedded Discord message with a breakdown of the status of each
+/// game instance.
+impl FormatEmbedded<'_, Vec<GameStatus>> for CreateEmbed {
+    fn format(&mut self, game_status: &Vec<GameStatus>) -> &mut Self {
+        let overall = game_status
+            .iter()
+            .find(|x| *x.status() != Status::Online)
+            .map(|x| x.status().clone())
+            .unwrap_or(Status::Online);
+
+        self.timestamp(Timestamp::now()).field(
+            "Overall status",
+            format!("{} {overall}", status_emoji(&overall)),
+            false,
+        );
+
+        game_status.iter().for_each(|instance| {
+            let maintenance = match instance.maintenance() {
+                Some(true) => "Yes",
+                Some(false) => "No",
+                None => "Not reported",
+            };
+            let impacted_features = Some(instance.impacted_features().join(", "))
+                .filter(|s| !s.is_empty())
+                .unwrap_or_else(|| "None".to_string());
+
+            self.field(
+                format!(
+                    "{} {}",
+                    status_emoji(instance.status()),
+                    instance.platform()
+                ),
+                format!(
+                    "{name}\nStatus: **{status}**\nMaintenance: **{maintenance}**\nCategory: {category}\nImpacted features: {impacted_features}",
+                    name = instance.name(),
+                    status = instance.status(),
+                    category = instance.category(),
+                ),
+                true,
+            );
+        });
+
+        self.field(
+            "Impacted features",
+            Some(
+                game_status
+                    .iter()
+                    .flat_map(|x| x.impacted_features())
+                    .collect::<HashSet<_>>()
+                    .iter()
+                    .fold(String::new(), |acc, next| acc + next + "\n"),
+            )
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| "None".to_string()),
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use siege_api::models::meta::SIEGE_TITLE;
+
+    use super::*;
+
+    fn load_status(filename: &str) -> Vec<GameStatus> {
+        let content = std::fs::read_to_string(filename).unwrap();
+        let statuses: Vec<GameStatus> = serde_json::from_str(content.as_str()).unwrap();
+        statuses
+            .into_iter()
+            .filter(|x| x.name().starts_with(SIEGE_TITLE))
+            .collect()
+    }
+
+    #[test]
+    fn format_validate() {
+        let mut embed = CreateEmbed::default();
+        let status = load_status("../samples/game-status-with-error.json");
+
+        embed.format(&status);
+
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        // Overall status, one for each instance, and the impacted features.
+        assert_eq!(fields.len(), status.len() + 2);
+        assert_eq!(fields[0].get("value").unwrap(), "🟡 Degraded");
+        assert_eq!(fields[3].get("name").unwrap(), "🟡 PC");
+        assert!(fields[3]
+            .get("value")
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .contains("Maintenance: **No**"));
+    }
+
+    #[test]
+    fn format_all_online() {
+        let mut embed = CreateEmbed::default();
+        let status = load_status("../samples/game-status.json");
+
+        embed.format(&status);
+
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        assert_eq!(fields[0].get("value").unwrap(), "🟢 Online");
+        assert_eq!(fields.last().unwrap().get("value").unwrap(), "None");
+    }
+}
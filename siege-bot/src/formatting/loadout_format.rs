@@ -0,0 +1,98 @@
+use serenity::builder::CreateEmbed;
+use siege_api::data::loadout::{Loadout, Weapon};
+
+use super::FormatEmbedded;
+
+fn format_weapons(weapons: &[Weapon]) -> String {
+    Some(
+        weapons
+            .iter()
+            .map(|weapon| format!("{} ({})", weapon.name(), weapon.kind()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+    .filter(|s| !s.is_empty())
+    .unwrap_or_else(|| "None".to_string())
+}
+
+/// Create an embedded Discord message with the weapons, gadgets and unique
+/// ability of an operator.
+impl FormatEmbedded<'_, Loadout> for CreateEmbed {
+    fn format(&mut self, loadout: &Loadout) -> &mut Self {
+        let gadgets = Some(
+            loadout
+                .gadgets()
+                .iter()
+                .map(|gadget| gadget.to_string())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "None".to_string());
+
+        self.field(
+            "Primary weapons",
+            format_weapons(loadout.primary_weapons()),
+            true,
+        )
+        .field(
+            "Secondary weapons",
+            format_weapons(loadout.secondary_weapons()),
+            true,
+        )
+        .field("Gadgets", gadgets, true)
+        .field(
+            "Unique ability",
+            loadout
+                .unique_ability()
+                .as_ref()
+                .map_or("None".to_string(), |ability| {
+                    format!("**{}**\n{}", ability.name(), ability.description())
+                }),
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use siege_api::operator::{get_loadout, Operator};
+
+    use super::*;
+
+    #[test]
+    fn format_validate() {
+        let mut embed = CreateEmbed::default();
+
+        embed.format(get_loadout(Operator::Buck));
+
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        assert_eq!(fields.len(), 4);
+        assert_eq!(
+            fields[0].get("value").unwrap(),
+            "C8-SFW (Assault rifle)\nCAMRS (Marksman rifle)"
+        );
+        assert_eq!(
+            fields[2].get("value").unwrap(),
+            "Stun grenade\nHard breach charge"
+        );
+        assert!(fields[3]
+            .get("value")
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .starts_with("**Skeleton Key**"));
+    }
+
+    #[test]
+    fn format_empty_loadout() {
+        let mut embed = CreateEmbed::default();
+
+        embed.format(get_loadout(Operator::Unknown));
+
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        fields.iter().for_each(|field| {
+            assert_eq!(field.get("value").unwrap(), "None");
+        });
+    }
+}
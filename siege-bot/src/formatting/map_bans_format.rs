@@ -0,0 +1,85 @@
+use serenity::builder::CreateEmbed;
+
+use crate::map_bans::{MapRecommendations, MapScore};
+
+use super::{FormatEmbedded, UNDEFINED};
+
+fn format_scores(scores: &[MapScore]) -> String {
+    Some(
+        scores
+            .iter()
+            .map(|x| {
+                format!(
+                    "**{}** `{}` (`{}` rounds, `{}` players)",
+                    x.map,
+                    x.win_rate()
+                        .map_or_else(|| UNDEFINED.to_string(), |x| format!("{:.1} %", 100.0 * x)),
+                    x.rounds_played,
+                    x.players
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+    .filter(|s| !s.is_empty())
+    .unwrap_or_else(|| "None".to_string())
+}
+
+/// Create an embedded Discord message with the maps a team should ban and
+/// pick, along with the round win rate the recommendation is based on.
+impl FormatEmbedded<'_, MapRecommendations> for CreateEmbed {
+    fn format(&mut self, recommendations: &MapRecommendations) -> &mut Self {
+        self.field("Ban", format_scores(&recommendations.bans), false)
+            .field("Pick", format_scores(&recommendations.picks), false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use siege_api::maps::Map;
+
+    use super::*;
+
+    fn score(map: Map, rounds_won: u64, rounds_played: u64) -> MapScore {
+        MapScore {
+            map,
+            rounds_won,
+            rounds_played,
+            players: 2,
+            score: 0.0,
+        }
+    }
+
+    #[test]
+    fn format_validate() {
+        let recommendations = MapRecommendations {
+            bans: vec![score(Map::Bank, 0, 0)],
+            picks: vec![score(Map::Border, 30, 40)],
+        };
+
+        let mut embed = CreateEmbed::default();
+        embed.format(&recommendations);
+
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        assert_eq!(fields[0].get("name").unwrap(), "Ban");
+        assert_eq!(
+            fields[0].get("value").unwrap(),
+            "**Bank** `—` (`0` rounds, `2` players)"
+        );
+        assert_eq!(fields[1].get("name").unwrap(), "Pick");
+        assert_eq!(
+            fields[1].get("value").unwrap(),
+            "**Border** `75.0 %` (`40` rounds, `2` players)"
+        );
+    }
+
+    #[test]
+    fn format_without_maps() {
+        let mut embed = CreateEmbed::default();
+        embed.format(&MapRecommendations::default());
+
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        assert_eq!(fields[0].get("value").unwrap(), "None");
+        assert_eq!(fields[1].get("value").unwrap(), "None");
+    }
+}
@@ -0,0 +1,92 @@
+use serenity::builder::CreateEmbed;
+use siege_api::data::map::{MapDetails, Playlist};
+
+use super::FormatEmbedded;
+
+/// Create an embedded Discord message with the release season, playlists and
+/// bomb sites of a map.
+impl FormatEmbedded<'_, MapDetails> for CreateEmbed {
+    fn format(&mut self, details: &MapDetails) -> &mut Self {
+        let playlists = Some(
+            details
+                .playlists()
+                .iter()
+                .map(|playlist| playlist.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "None".to_string());
+
+        self.field("Released", details.release_season(), true)
+            .field(
+                "Ranked pool",
+                if details.in_playlist(Playlist::Ranked) {
+                    "Yes"
+                } else {
+                    "No"
+                },
+                true,
+            )
+            .field("Playlists", playlists, true);
+
+        details
+            .sites_by_floor()
+            .iter()
+            .rev()
+            .for_each(|(floor, sites)| {
+                self.field(
+                    format!("Sites on {floor}"),
+                    sites
+                        .iter()
+                        .map(|site| site.name().as_str())
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                    true,
+                );
+            });
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use siege_api::maps::{get_map_details, Map};
+
+    use super::*;
+
+    #[test]
+    fn format_validate() {
+        let mut embed = CreateEmbed::default();
+
+        embed.format(get_map_details(Map::ClubHouse));
+
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        assert_eq!(fields.len(), 6);
+        assert_eq!(fields[0].get("value").unwrap(), "Y0S0");
+        assert_eq!(fields[1].get("value").unwrap(), "Yes");
+        assert_eq!(
+            fields[2].get("value").unwrap(),
+            "Ranked, Unranked, Quick match"
+        );
+        assert_eq!(fields[3].get("name").unwrap(), "Sites on 2F");
+        assert_eq!(
+            fields[3].get("value").unwrap(),
+            "Gym / Bedroom\nCCTV Room / Cash Room"
+        );
+        assert_eq!(fields[5].get("name").unwrap(), "Sites on B");
+    }
+
+    #[test]
+    fn format_without_sites() {
+        let mut embed = CreateEmbed::default();
+
+        embed.format(get_map_details(Map::CloseQuarter));
+
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields[1].get("value").unwrap(), "No");
+        assert_eq!(fields[2].get("value").unwrap(), "Team deathmatch");
+    }
+}
@@ -0,0 +1,147 @@
+use serenity::{builder::CreateEmbed, model::Timestamp};
+use siege_api::data::operator::OperatorDetails;
+
+use super::FormatEmbedded;
+
+/// Create an embedded Discord message with the biography and kit of an operator.
+impl FormatEmbedded<'_, OperatorDetails> for CreateEmbed {
+    fn format(&mut self, details: &OperatorDetails) -> &mut Self {
+        let roles = Some(
+            details
+                .roles()
+                .iter()
+                .map(|role| role.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "None".to_string());
+
+        self.timestamp(Timestamp::now())
+            .field("Real name", details.realname(), true)
+            .field(
+                "Age",
+                format!("{} ({})", details.age(), details.date_of_birth()),
+                true,
+            )
+            .field("Birthplace", details.birthplace(), true)
+            .field("Unit", details.unit(), true)
+            .field(
+                "Country",
+                Some(details.country_code().as_str())
+                    .filter(|s| !s.is_empty())
+                    .unwrap_or("Unknown"),
+                true,
+            )
+            .field("Side", details.side(), true)
+            .field("Health", details.health(), true)
+            .field("Speed", details.speed(), true)
+            .field("Season introduced", details.season_introduced(), true)
+            .field("Roles", roles, false)
+    }
+}
+
+/// Maximum number of operators listed in a single message, to stay within
+/// the length limit of the embed description.
+const OPERATOR_LIST_LIMIT: usize = 50;
+
+/// Create an embedded Discord message listing operators with their speed and roles.
+impl FormatEmbedded<'_, Vec<&OperatorDetails>> for CreateEmbed {
+    fn format(&mut self, operators: &Vec<&OperatorDetails>) -> &mut Self {
+        let mut lines = operators
+            .iter()
+            .take(OPERATOR_LIST_LIMIT)
+            .map(|details| {
+                format!(
+                    "**{}** ({}, {} speed) {}",
+                    details.name(),
+                    details.side(),
+                    details.speed(),
+                    details
+                        .roles()
+                        .iter()
+                        .map(|role| role.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+            .collect::<Vec<_>>();
+        if operators.len() > OPERATOR_LIST_LIMIT {
+            lines.push(format!(
+                "... and {} more",
+                operators.len() - OPERATOR_LIST_LIMIT
+            ));
+        }
+
+        self.timestamp(Timestamp::now())
+            .description(lines.join("\n"))
+            .footer(|footer| footer.text(format!("{} operators found", operators.len())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use siege_api::{
+        data::operator::catalogue,
+        operator::{get_operator_details, Operator},
+    };
+
+    use super::*;
+
+    #[test]
+    fn format_validate() {
+        let mut embed = CreateEmbed::default();
+
+        embed.format(get_operator_details(Operator::Brava));
+
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        assert_eq!(fields.len(), 10);
+        assert_eq!(fields[0].get("value").unwrap(), "Nayara Cardoso");
+        assert_eq!(fields[5].get("value").unwrap(), "Attacker");
+        assert_eq!(fields[6].get("value").unwrap(), "Low");
+        assert_eq!(fields[7].get("value").unwrap(), "Fast");
+        assert_eq!(fields[8].get("value").unwrap(), "Y8S1");
+        assert_eq!(fields[9].get("value").unwrap(), "IntelGatherer, AntiGadget");
+    }
+
+    #[test]
+    fn format_without_roles() {
+        let mut embed = CreateEmbed::default();
+
+        embed.format(get_operator_details(Operator::Recruit));
+
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        assert_eq!(fields[4].get("value").unwrap(), "Unknown");
+        assert_eq!(fields[9].get("value").unwrap(), "None");
+    }
+
+    #[test]
+    fn format_operator_list() {
+        let mut embed = CreateEmbed::default();
+        let operators = vec![
+            get_operator_details(Operator::Brava),
+            get_operator_details(Operator::Ram),
+        ];
+
+        embed.format(&operators);
+
+        assert_eq!(
+            embed.0.get("description").unwrap(),
+            "**Brava** (Attacker, Fast speed) IntelGatherer, AntiGadget\n**Ram** (Attacker, Slow speed) Breach"
+        );
+    }
+
+    #[test]
+    fn format_operator_list_is_truncated() {
+        let mut embed = CreateEmbed::default();
+        let operators = catalogue().iter().collect::<Vec<_>>();
+
+        embed.format(&operators);
+
+        let description = embed.0.get("description").unwrap().as_str().unwrap();
+        assert_eq!(description.lines().count(), OPERATOR_LIST_LIMIT + 1);
+        assert!(description
+            .ends_with(format!("... and {} more", operators.len() - OPERATOR_LIST_LIMIT).as_str()));
+        assert!(description.len() <= 4096);
+    }
+}
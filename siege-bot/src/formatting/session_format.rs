@@ -0,0 +1,141 @@
+use chrono::Duration;
+use serenity::{builder::CreateEmbed, model::Timestamp, utils::Color};
+
+use crate::play_session::SessionSummary;
+
+use super::{decimal, percentage, FormatEmbedded};
+
+/// Format the length of a session, e.g. `1 h 35 min`.
+fn length(duration: Duration) -> String {
+    match (duration.num_hours(), duration.num_minutes() % 60) {
+        (0, minutes) => format!("{minutes} min"),
+        (hours, minutes) => format!("{hours} h {minutes} min"),
+    }
+}
+
+/// Create an embedded Discord message with the matches, kills and deaths, rank
+/// points and operators played in a session.
+impl FormatEmbedded<'_, SessionSummary> for CreateEmbed {
+    fn format(&mut self, summary: &SessionSummary) -> &mut Self {
+        self.description(format!("Played for **{}**", length(summary.duration)))
+            .timestamp(Timestamp::now())
+            .color(Color::DARK_RED)
+            .field(
+                "Match",
+                format!(
+                    "Matches {total} - **{win_rate}** - Wins **{wins}** / Losses **{losses}**",
+                    total = summary.matches(),
+                    win_rate = percentage(summary.win_rate()),
+                    wins = summary.wins,
+                    losses = summary.losses,
+                ),
+                false,
+            )
+            .field(
+                "Kill/death",
+                format!(
+                    "K/D: **{kd}** - Kills {kills} / Deaths {deaths}",
+                    kd = decimal(summary.kd()),
+                    kills = summary.kills,
+                    deaths = summary.deaths,
+                ),
+                false,
+            );
+
+        if let Some(rank_points) = summary.rank_points {
+            self.field("Rank points", format!("**{rank_points:+}** RP"), true);
+        }
+
+        let operators = summary
+            .operators
+            .iter()
+            .map(|(name, rounds)| format!("**{name}** {rounds} rounds"))
+            .collect::<Vec<_>>();
+        self.field(
+            "Most played operators",
+            match operators.is_empty() {
+                true => "No rounds played".to_string(),
+                false => operators.join("\n"),
+            },
+            true,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn field(embed: &CreateEmbed, name: &str) -> Option<String> {
+        embed
+            .0
+            .get("fields")
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|x| x.get("name").unwrap() == name)
+            .map(|x| x.get("value").unwrap().as_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn format_validate() {
+        let summary = SessionSummary {
+            duration: Duration::minutes(95),
+            wins: 3,
+            losses: 2,
+            kills: 30,
+            deaths: 20,
+            rank_points: Some(-12),
+            operators: vec![("Ash".to_string(), 15), ("Zofia".to_string(), 8)],
+        };
+
+        let mut embed = CreateEmbed::default();
+        embed.format(&summary);
+
+        assert_eq!(
+            embed.0.get("description").unwrap(),
+            "Played for **1 h 35 min**"
+        );
+        assert_eq!(
+            field(&embed, "Match").unwrap(),
+            "Matches 5 - **60.00 %** - Wins **3** / Losses **2**"
+        );
+        assert_eq!(
+            field(&embed, "Kill/death").unwrap(),
+            "K/D: **1.50** - Kills 30 / Deaths 20"
+        );
+        assert_eq!(field(&embed, "Rank points").unwrap(), "**-12** RP");
+        assert_eq!(
+            field(&embed, "Most played operators").unwrap(),
+            "**Ash** 15 rounds\n**Zofia** 8 rounds"
+        );
+    }
+
+    #[test]
+    fn format_without_matches() {
+        let summary = SessionSummary {
+            duration: Duration::minutes(20),
+            wins: 0,
+            losses: 0,
+            kills: 0,
+            deaths: 0,
+            rank_points: None,
+            operators: Vec::new(),
+        };
+
+        let mut embed = CreateEmbed::default();
+        embed.format(&summary);
+
+        assert_eq!(embed.0.get("description").unwrap(), "Played for **20 min**");
+        assert_eq!(
+            field(&embed, "Match").unwrap(),
+            "Matches 0 - **—** - Wins **0** / Losses **0**"
+        );
+        assert_eq!(field(&embed, "Rank points"), None);
+        assert_eq!(
+            field(&embed, "Most played operators").unwrap(),
+            "No rounds played"
+        );
+    }
+}
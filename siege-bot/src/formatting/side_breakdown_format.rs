@@ -0,0 +1,59 @@
+use serenity::builder::CreateEmbed;
+use siege_api::{
+    models::{OperatorStatistics, SideOrAll, SideTotals, Statistics},
+    operator::get_operator_details,
+};
+
+use super::{decimal, percentage, FormatEmbedded};
+
+/// Create an embedded Discord message comparing the round win rate and K/D of
+/// each side, e.g. for a map on attack and defense.
+impl FormatEmbedded<'_, Vec<(SideOrAll, &Statistics)>> for CreateEmbed {
+    fn format(&mut self, sides: &Vec<(SideOrAll, &Statistics)>) -> &mut Self {
+        let lines = sides
+            .iter()
+            .map(|(side, statistics)| {
+                format!(
+                    "**{side}** R: `{}` (`{}`) K/D: `{:.2}`",
+                    percentage(statistics.rounds_win_rate()),
+                    statistics.rounds_played(),
+                    statistics.kill_death_ratio()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if lines.is_empty() {
+            return self;
+        }
+
+        self.field("Attack vs defense", lines.join("\n"), false)
+    }
+}
+
+/// Create an embedded Discord message comparing an operator with all rounds
+/// played on the side of the operator.
+impl FormatEmbedded<'_, (&OperatorStatistics, SideTotals)> for CreateEmbed {
+    fn format(&mut self, (operator, totals): &(&OperatorStatistics, SideTotals)) -> &mut Self {
+        let side = get_operator_details(*operator.name()).side();
+        let statistics = operator.statistics();
+
+        self.field(
+            format!("Compared to all {side} rounds"),
+            format!(
+                "Share of rounds: `{}` (`{}` of `{}`)\nRound win rate: `{}` vs `{}`\nK/D: `{:.2}` vs `{}`",
+                percentage(
+                    (*totals.rounds_played() > 0).then(|| {
+                        *statistics.rounds_played() as f64 / *totals.rounds_played() as f64
+                    })
+                ),
+                statistics.rounds_played(),
+                totals.rounds_played(),
+                percentage(statistics.rounds_win_rate()),
+                percentage(totals.rounds_win_rate()),
+                statistics.kill_death_ratio(),
+                decimal(totals.kd()),
+            ),
+            false,
+        )
+    }
+}
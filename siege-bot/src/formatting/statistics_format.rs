@@ -1,7 +1,7 @@
 use serenity::{builder::CreateEmbed, model::Timestamp};
 use siege_api::models::Statistics;
 
-use super::FormatEmbedded;
+use super::{decimal, percentage, FormatEmbedded};
 
 /// Create an embedded Discord message with statistics information.
 impl FormatEmbedded<'_, Statistics> for CreateEmbed {
@@ -36,10 +36,7 @@ impl FormatEmbedded<'_, Statistics> for CreateEmbed {
                     statistics.opening_deaths(),
                 ),
             ),
-            (
-                "Opening winrate",
-                format!("{:.2} %", 100f64 * statistics.opening_win_rate()),
-            ),
+            ("Opening winrate", percentage(statistics.opening_win_rate())),
             (
                 "Rounds survived",
                 format!("{:.2} %", 100f64 * statistics.rounds_survived()),
@@ -78,6 +75,23 @@ impl FormatEmbedded<'_, Statistics> for CreateEmbed {
             ),
         ];
 
+        let metrics = statistics.derived_metrics();
+        let performance = [
+            ("Rating", decimal(*metrics.rating())),
+            ("Entry success", percentage(*metrics.entry_success())),
+            ("Clutch rate", percentage(*metrics.clutch_rate())),
+            ("Trade efficiency", percentage(*metrics.trade_efficiency())),
+            ("Assists per round", decimal(*metrics.assists_per_round())),
+            (
+                "Headshots of kills",
+                percentage(*metrics.headshot_percentage()),
+            ),
+        ]
+        .iter()
+        .map(|(name, value)| format!("{name}: **{value}**"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
         let names = values.iter().map(|x| x.0).collect::<Vec<_>>().join("\n");
         let values = values
             .iter()
@@ -101,8 +115,8 @@ impl FormatEmbedded<'_, Statistics> for CreateEmbed {
             .field(
                 "Matches",
                 format!(
-                    "Win rate **{win_rate:.2} %** Played/Win/Lost: **{total}** / **{wins}** / **{lost}**",
-                    win_rate = 100f64 * statistics.matches_win_rate(),
+                    "Win rate **{win_rate}** Played/Win/Lost: **{total}** / **{wins}** / **{lost}**",
+                    win_rate = percentage(statistics.matches_win_rate()),
                     total = statistics.matches_played(),
                     wins = statistics.matches_won(),
                     lost = statistics.matches_lost(),
@@ -112,14 +126,15 @@ impl FormatEmbedded<'_, Statistics> for CreateEmbed {
             .field(
                 "Rounds",
                 format!(
-                    "Win rate **{win_rate:.2} %** Played/Win/Lost: **{total}** / **{wins}** / **{lost}**",
-                    win_rate = 100f64 * statistics.rounds_win_rate(),
+                    "Win rate **{win_rate}** Played/Win/Lost: **{total}** / **{wins}** / **{lost}**",
+                    win_rate = percentage(statistics.rounds_win_rate()),
                     total = statistics.rounds_played(),
                     wins = statistics.rounds_won(),
                     lost = statistics.rounds_lost(),
                 ),
                 false,
             )
+            .field("Performance", performance, false)
             .field("Statistic", names, true)
             .field("Value", values, true)
     }
@@ -131,7 +146,7 @@ mod test {
 
     use chrono::{DateTime, Utc};
     use siege_api::{
-        models::{AllOrRanked, StatisticResponse},
+        models::{GameModeOrAll, StatisticResponse},
         operator,
     };
 
@@ -143,7 +158,7 @@ mod test {
         let content = std::fs::read_to_string("../samples/operators.json").unwrap();
         let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
         let operator = stats
-            .get_operator(operator::Operator::Hibana, AllOrRanked::All)
+            .get_operator(operator::Operator::Hibana, GameModeOrAll::All)
             .unwrap();
 
         embed.format(operator.statistics());
@@ -162,6 +177,26 @@ mod test {
                 < 1
         );
 
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        let performance = fields
+            .iter()
+            .find(|x| x.get("name").unwrap() == "Performance")
+            .unwrap();
+        assert!(performance
+            .get("value")
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .starts_with("Rating: **"));
+
+        // Hibana has no opening duels, so the entry success is undefined.
+        assert!(performance
+            .get("value")
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .contains("Entry success: **—**"));
+
         println!("{embed:?}");
     }
 }
@@ -0,0 +1,57 @@
+use serenity::builder::CreateEmbed;
+
+use crate::team::TeamSide;
+
+use super::FormatEmbedded;
+
+/// Create an embedded Discord message with the operator mains of each player
+/// on one side, the roles they cover and the key roles nobody mains.
+impl FormatEmbedded<'_, TeamSide> for CreateEmbed {
+    fn format(&mut self, team: &TeamSide) -> &mut Self {
+        team.players.iter().for_each(|player| {
+            let mains = Some(
+                player
+                    .operators
+                    .iter()
+                    .map(|x| {
+                        format!(
+                            "**{}** `{}` rounds, K/D `{:.2}`",
+                            x.operator, x.rounds_played, x.kill_death_ratio
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| "No operators played".to_string());
+
+            self.field(
+                format!("{} mains of {}", team.side, player.name),
+                mains,
+                true,
+            );
+        });
+
+        let coverage = Some(
+            team.coverage
+                .iter()
+                .map(|(role, count)| format!("{role} ({count})"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "None".to_string());
+        let gaps = Some(
+            team.gaps
+                .iter()
+                .map(|name| format!("No {name} main"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "None".to_string());
+
+        self.field(format!("{} role coverage", team.side), coverage, false)
+            .field(format!("{} gaps", team.side), gaps, false)
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    collections::HashMap,
+    fs::{read_to_string, write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+use serenity::{
+    model::prelude::GuildId,
+    prelude::{RwLock, TypeMap, TypeMapKey},
+};
+use siege_api::models::{GameMode, GameModeOrAll, PlatformFamily};
+
+use crate::config::Defaults;
+
+pub struct GuildSettings;
+impl TypeMapKey for GuildSettings {
+    type Value = Arc<RwLock<dyn SettingsLookup>>;
+}
+
+/// Defaults a guild has changed with the `settings` command. Settings which
+/// are not set fall back to the configured defaults.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    #[serde(with = "display_from_str")]
+    pub platform: Option<PlatformFamily>,
+    #[serde(with = "display_from_str")]
+    pub game_mode: Option<GameModeOrAll>,
+    #[serde(with = "display_from_str")]
+    pub statistics_game_mode: Option<GameMode>,
+    pub minimum_rounds: Option<u64>,
+    pub ephemeral: Option<bool>,
+}
+
+impl Settings {
+    /// Override the configured defaults with the settings of the guild.
+    pub fn apply(&self, defaults: Defaults) -> Defaults {
+        Defaults {
+            platform: self.platform.unwrap_or(defaults.platform),
+            game_mode: self.game_mode.unwrap_or(defaults.game_mode),
+            statistics_game_mode: self
+                .statistics_game_mode
+                .unwrap_or(defaults.statistics_game_mode),
+            minimum_rounds: self.minimum_rounds.unwrap_or(defaults.minimum_rounds),
+            ephemeral: self.ephemeral.unwrap_or(defaults.ephemeral),
+        }
+    }
+}
+
+/// Get the configured defaults, changed by the settings of the guild.
+pub async fn guild_defaults(data: &TypeMap, guild_id: Option<GuildId>) -> Defaults {
+    let defaults = *data
+        .get::<Defaults>()
+        .expect("defaults are always registered");
+
+    match guild_id {
+        Some(guild_id) => data
+            .get::<GuildSettings>()
+            .expect("always registered")
+            .read()
+            .await
+            .get(&guild_id)
+            .apply(defaults),
+        None => defaults,
+    }
+}
+
+/// Keeps track of the settings of each guild.
+#[cfg_attr(test, mockall::automock)]
+pub trait SettingsLookup: Send + Sync {
+    /// Get the settings of a guild, which are empty if it never changed any.
+    fn get(&self, guild_id: &GuildId) -> Settings;
+    fn insert(&mut self, guild_id: &GuildId, settings: Settings) -> Result<(), std::io::Error>;
+    fn remove(&mut self, guild_id: &GuildId) -> Result<(), std::io::Error>;
+}
+
+#[derive(Debug)]
+pub struct SettingsLookupImpl {
+    path: PathBuf,
+    settings: HashMap<GuildId, Settings>,
+}
+
+impl SettingsLookup for SettingsLookupImpl {
+    fn get(&self, guild_id: &GuildId) -> Settings {
+        self.settings.get(guild_id).copied().unwrap_or_default()
+    }
+
+    /// Replace the settings of a guild.
+    fn insert(&mut self, guild_id: &GuildId, settings: Settings) -> Result<(), std::io::Error> {
+        self.settings.insert(*guild_id, settings);
+        self.persist()
+    }
+
+    /// Reset the guild to the configured defaults.
+    fn remove(&mut self, guild_id: &GuildId) -> Result<(), std::io::Error> {
+        self.settings.remove(guild_id);
+        self.persist()
+    }
+}
+
+impl SettingsLookupImpl {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let settings = match read_to_string(path) {
+            Ok(content) => serde_json::from_str(content.as_str())?,
+            Err(err) => {
+                tracing::warn!("Failed to read guild settings. Creating default. Error: {err:?}");
+                HashMap::default()
+            }
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            settings,
+        })
+    }
+
+    fn persist(&self) -> Result<(), std::io::Error> {
+        let content =
+            serde_json::to_string_pretty(&self.settings).expect("should always be serializeable");
+        write(&self.path, content)
+    }
+}
+
+/// Store the enums from the Siege API, which are not serializable, by name.
+mod display_from_str {
+    use std::{fmt::Display, str::FromStr};
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S, T>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Display,
+    {
+        match value {
+            Some(value) => serializer.collect_str(value),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr,
+        T::Err: Display,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|err| serde::de::Error::custom(format!("`{value}`: {err}")))
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn load_from_disk_without_existing_file() {
+        assert!(SettingsLookupImpl::load("not existing file").is_ok());
+    }
+
+    #[test]
+    fn apply_overrides_only_set_values() {
+        let settings = Settings {
+            game_mode: Some(GameModeOrAll::Ranked),
+            ephemeral: Some(true),
+            ..Default::default()
+        };
+
+        let defaults = settings.apply(Defaults::default());
+        assert_eq!(defaults.platform, PlatformFamily::Pc);
+        assert_eq!(defaults.game_mode, GameModeOrAll::Ranked);
+        assert_eq!(defaults.minimum_rounds, 0);
+        assert!(defaults.ephemeral);
+    }
+
+    #[test]
+    fn insert_load_and_remove() {
+        let guild_id = GuildId::from(1290213);
+        let settings = Settings {
+            platform: Some(PlatformFamily::Console),
+            statistics_game_mode: Some(GameMode::Ranked),
+            minimum_rounds: Some(25),
+            ..Default::default()
+        };
+
+        let file = NamedTempFile::new().unwrap();
+        let filename = file.path().to_str().unwrap().to_string();
+        std::fs::write(&filename, "{}").unwrap();
+
+        let mut lookup = SettingsLookupImpl::load(filename.as_str()).unwrap();
+        lookup
+            .insert(&guild_id, settings)
+            .expect("should be able to persist");
+
+        // Act - Load from the exsiting file
+        let mut lookup = SettingsLookupImpl::load(filename.as_str()).unwrap();
+        assert_eq!(lookup.get(&guild_id), settings);
+
+        lookup.remove(&guild_id).expect("should be able to persist");
+        let lookup = SettingsLookupImpl::load(filename.as_str()).unwrap();
+        assert_eq!(lookup.get(&guild_id), Settings::default());
+    }
+}
@@ -1,60 +1,135 @@
 use async_trait::async_trait;
 use serenity::{
-    model::prelude::{interaction::Interaction, Guild, GuildId, Ready},
+    model::prelude::{command::Command, interaction::Interaction, Guild, GuildId, Ready},
     prelude::*,
 };
 
-use crate::commands::{
-    add_player::AddPlayerCommand, all_maps::AllMapsCommand, all_operators::AllOperatorCommand,
-    game_status::GameStatusCommand, id::IdCommand, map::MapCommand, operator::OperatorCommand,
-    ping::PingCommand, statistics::StatisticsCommand, AutocompleteHandler, CommandError,
-    CommandHandler,
+use crate::{
+    commands::{
+        add_player::AddPlayerCommand, all_maps::AllMapsCommand, all_operators::AllOperatorCommand,
+        defaults, discord_app_command::CommandInteraction, game_status::GameStatusCommand,
+        id::IdCommand, map::MapCommand, map_bans::MapBansCommand,
+        notifications::NotificationsCommand, operator::OperatorCommand,
+        operator_info::OperatorInfoCommand, operators_by::OperatorsByCommand, ping::PingCommand,
+        registry::CommandRegistry, roulette::RouletteCommand, session::SessionCommand,
+        settings::SettingsCommand, statistics::StatisticsCommand, sync_rank::SyncRankCommand,
+        team::TeamCommand,
+    },
+    config::{CommandsConfig, ConfigError, Registration},
+    pagination,
 };
 
-#[derive(Default)]
-pub(crate) struct Handler;
-
-async fn sync_commands(guild_id: GuildId, ctx: &Context) {
-    tracing::info!("Syncing commands to {guild_id}");
-    match GuildId::set_application_commands(&guild_id, &ctx.http, |commands| {
-        commands
-            .create_application_command(PingCommand::register)
-            .create_application_command(IdCommand::register)
-            .create_application_command(StatisticsCommand::register)
-            .create_application_command(MapCommand::register)
-            .create_application_command(OperatorCommand::register)
-            .create_application_command(AddPlayerCommand::register)
-            .create_application_command(AllOperatorCommand::register)
-            .create_application_command(AllMapsCommand::register)
-            .create_application_command(GameStatusCommand::register)
-    })
-    .await
-    {
-        Ok(commands) => tracing::trace!("Create guild slash commands: {commands:#?}"),
-        Err(err) => tracing::error!("Failed to create guild commands: {err:#?}"),
-    };
+pub(crate) struct Handler {
+    registry: CommandRegistry,
+    config: CommandsConfig,
+}
+
+impl Handler {
+    /// Create the handler with the commands enabled in the configuration.
+    pub fn new(config: &CommandsConfig) -> Result<Self, ConfigError> {
+        let registry = CommandRegistry::default()
+            .command::<PingCommand>()
+            .command::<IdCommand>()
+            .command::<StatisticsCommand>()
+            .command_with_autocomplete::<MapCommand>()
+            .command::<MapBansCommand>()
+            .command_with_autocomplete::<OperatorCommand>()
+            .command_with_autocomplete::<OperatorInfoCommand>()
+            .command::<OperatorsByCommand>()
+            .command::<AddPlayerCommand>()
+            .command::<AllOperatorCommand>()
+            .command::<AllMapsCommand>()
+            .command::<GameStatusCommand>()
+            .command::<NotificationsCommand>()
+            .command::<RouletteCommand>()
+            .command::<TeamCommand>()
+            .command::<SettingsCommand>()
+            .command::<SyncRankCommand>()
+            .command::<SessionCommand>();
+
+        let registry = match config.enabled.as_deref() {
+            Some(enabled) => registry.only(enabled)?,
+            None => registry,
+        };
+        Ok(Self {
+            registry,
+            config: config.clone(),
+        })
+    }
+
+    /// Register the commands globally, or remove the global commands left
+    /// from running with global registration.
+    async fn sync_global_commands(&self, ctx: &Context) {
+        let result = match self.config.registration {
+            Registration::Global => {
+                tracing::info!("Syncing global commands");
+                Command::set_global_application_commands(&ctx.http, |commands| {
+                    self.registry.register(commands)
+                })
+                .await
+            }
+            Registration::Guild => {
+                match Command::get_global_application_commands(&ctx.http).await {
+                    Ok(commands) if commands.is_empty() => return,
+                    Ok(commands) => {
+                        tracing::info!("Removing {} stale global commands", commands.len());
+                        Command::set_global_application_commands(&ctx.http, |commands| commands)
+                            .await
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+        };
+
+        match result {
+            Ok(commands) => tracing::trace!("Global slash commands: {commands:#?}"),
+            Err(err) => tracing::error!("Failed to sync global commands: {err:#?}"),
+        }
+    }
+
+    /// Register the commands in the guild, or remove the commands left from
+    /// earlier runs if they should not be registered in it.
+    async fn sync_guild_commands(&self, guild_id: GuildId, ctx: &Context) {
+        let result = if self.config.registers_in_guild(guild_id) {
+            tracing::info!("Syncing commands to {guild_id}");
+            guild_id
+                .set_application_commands(&ctx.http, |commands| self.registry.register(commands))
+                .await
+        } else {
+            match guild_id.get_application_commands(&ctx.http).await {
+                Ok(commands) if commands.is_empty() => return,
+                Ok(commands) => {
+                    tracing::info!("Removing {} stale commands from {guild_id}", commands.len());
+                    guild_id
+                        .set_application_commands(&ctx.http, |commands| commands)
+                        .await
+                }
+                Err(err) => Err(err),
+            }
+        };
+
+        match result {
+            Ok(commands) => tracing::trace!("Guild slash commands: {commands:#?}"),
+            Err(err) => tracing::error!("Failed to sync commands to {guild_id}: {err:#?}"),
+        }
+    }
 }
 
 #[async_trait]
 impl EventHandler for Handler {
-    async fn ready(&self, _ctx: Context, ready: Ready) {
+    async fn ready(&self, ctx: Context, ready: Ready) {
         tracing::info!("Client is ready");
         tracing::trace!("{ready:?}");
 
-        for guild in ready.guilds {
-            sync_commands(guild.id, &_ctx).await;
-        }
+        self.sync_global_commands(&ctx).await;
     }
 
+    /// Sent for every guild when connecting, and when the bot joins a new
+    /// guild while running.
     async fn guild_create(&self, ctx: Context, guild: Guild) {
         tracing::info!("Connecting to guild: {:?}", guild.id);
 
-        let guild_id = guild.id;
-
-        GuildId::get_application_commands(&guild_id, &ctx.http)
-            .await
-            .iter()
-            .for_each(|id| tracing::debug!("Commands: {id:#?}"));
+        self.sync_guild_commands(guild.id, &ctx).await;
     }
 
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
@@ -65,18 +140,9 @@ impl EventHandler for Handler {
                     guild_id = command.guild_id
                 );
 
-                let result = match command.data.name.as_str() {
-                    "ping" => PingCommand::run(&ctx, &command).await,
-                    "id" => IdCommand::run(&ctx, &command).await,
-                    "statistics" => StatisticsCommand::run(&ctx, &command).await,
-                    "operator" => OperatorCommand::run(&ctx, &command).await,
-                    "map" => MapCommand::run(&ctx, &command).await,
-                    "add" => AddPlayerCommand::run(&ctx, &command).await,
-                    "all_operators" => AllOperatorCommand::run(&ctx, &command).await,
-                    "all_maps" => AllMapsCommand::run(&ctx, &command).await,
-                    "status" => GameStatusCommand::run(&ctx, &command).await,
-                    _ => Err(CommandError::CommandNotFound),
-                };
+                let command = CommandInteraction::from(command);
+                command.set_ephemeral(defaults(&ctx, &command).await.ephemeral);
+                let result = self.registry.run(&ctx, &command).await;
 
                 if let Err(why) = result {
                     tracing::error!("Failed to response to command: {why}");
@@ -85,18 +151,23 @@ impl EventHandler for Handler {
             Interaction::Autocomplete(autocomplete) => {
                 tracing::trace!("Autocomplete request: {autocomplete:#?}");
 
-                match autocomplete.data.name.as_str() {
-                    "operator" => {
-                        OperatorCommand::handle_autocomplete(&ctx, &autocomplete)
-                            .await
-                            .unwrap();
-                    }
-                    "map" => {
-                        MapCommand::handle_autocomplete(&ctx, &autocomplete)
-                            .await
-                            .unwrap();
-                    }
-                    name => tracing::warn!("Autocomplete for {name} not handled"),
+                if let Err(why) = self.registry.autocomplete(&ctx, &autocomplete).await {
+                    tracing::error!(
+                        "Failed to autocomplete {name}: {why}",
+                        name = autocomplete.data.name
+                    );
+                }
+            }
+            Interaction::MessageComponent(component) => {
+                tracing::trace!("Component interaction: {component:#?}");
+
+                match pagination::handle_component(&ctx, &component).await {
+                    Some(Err(why)) => tracing::error!("Failed to change page: {why}"),
+                    Some(Ok(())) => {}
+                    None => tracing::warn!(
+                        "Unhandled component: {custom_id}",
+                        custom_id = component.data.custom_id
+                    ),
                 }
             }
             _ => tracing::warn!("Unhandled interation: {interaction:?}"),
@@ -1,8 +1,19 @@
 mod commands;
+pub mod config;
 mod constants;
+pub mod digest;
 pub mod formatting;
+pub mod guild_settings;
 pub mod handler;
+pub mod map_bans;
+pub mod notification_channels;
+pub mod pagination;
+pub mod play_session;
+pub mod rank_roles;
+pub mod rendering;
 pub mod siege_player_lookup;
+pub mod status_watcher;
+pub mod team;
 
 use serenity::{
     model::prelude::*,
@@ -11,11 +22,20 @@ use serenity::{
 };
 use siege_api::auth::Auth;
 use siege_player_lookup::SiegePlayerLookup;
-use std::{env::var, error::Error, sync::Arc};
+use std::{error::Error, sync::Arc};
 
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
-use crate::{handler::Handler, siege_player_lookup::PlayerLookupImpl};
+use crate::{
+    config::{Config, Defaults, RankRolesConfig},
+    digest::RankPointSnapshots,
+    guild_settings::{GuildSettings, SettingsLookupImpl},
+    handler::Handler,
+    notification_channels::{ChannelLookupImpl, NotificationChannels},
+    pagination::{PaginationSessions, Paginations},
+    play_session::{PlaySessionLookupImpl, PlaySessions},
+    siege_player_lookup::PlayerLookupImpl,
+};
 
 struct SiegeApi;
 impl TypeMapKey for SiegeApi {
@@ -24,33 +44,64 @@ impl TypeMapKey for SiegeApi {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("Failed to load the configuration: {err}");
+            std::process::exit(1);
+        }
+    };
+    let handler = match Handler::new(&config.commands) {
+        Ok(handler) => handler,
+        Err(err) => {
+            eprintln!("Failed to load the configuration: {err}");
+            std::process::exit(1);
+        }
+    };
+
     // Setup tracing
-    let file_appender = tracing_appender::rolling::never(
-        var("LOGS_DIR").unwrap_or_else(|_| "./logs/".to_string()),
-        "siege-bot.log",
-    );
+    let file_appender =
+        tracing_appender::rolling::never(&config.logging.directory, "siege-bot.log");
     // `_guard` is needed to ensure logs are flush when dropped.
     let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
+    let filter = config
+        .logging
+        .filter_directives()?
+        .into_iter()
+        .fold(EnvFilter::from_default_env(), |filter, directive| {
+            filter.add_directive(directive)
+        });
     tracing_subscriber::registry()
         .with(fmt::layer().with_writer(non_blocking))
         .with(fmt::layer())
-        .with(
-            EnvFilter::from_default_env()
-                .add_directive("siege_bot=debug".parse()?)
-                .add_directive("siege_api=info".parse()?),
-        )
+        .with(filter)
         .init();
 
-    let token = var("DISCORD_TOKEN").expect("environment variable `DISCORD_TOKEN` should be set");
-    let intents = GatewayIntents::MESSAGE_CONTENT
-        | GatewayIntents::GUILD_MESSAGES
-        | GatewayIntents::MESSAGE_CONTENT;
-    let mut client = Client::builder(token, intents)
-        .event_handler(Handler)
+    // `GUILDS` is needed to receive `guild_create` when joining a guild.
+    let intents =
+        GatewayIntents::GUILDS | GatewayIntents::GUILD_MESSAGES | GatewayIntents::MESSAGE_CONTENT;
+    let mut client = Client::builder(&config.discord.token, intents)
+        .event_handler(handler)
         .await
         .expect("Error creating client");
 
-    setup_type_map(&mut client).await?;
+    setup_type_map(&mut client, &config).await?;
+
+    tokio::spawn(status_watcher::watch(
+        client.cache_and_http.http.clone(),
+        client.data.clone(),
+        config.cache.status_poll_interval(),
+    ));
+    tokio::spawn(rank_roles::watch(
+        client.cache_and_http.http.clone(),
+        client.data.clone(),
+    ));
+    tokio::spawn(digest::watch(
+        client.cache_and_http.http.clone(),
+        client.data.clone(),
+        config.digest.clone(),
+        RankPointSnapshots::load(config.storage.file(".digest.json"))?,
+    ));
 
     let shard_manager = client.shard_manager.clone();
     tokio::spawn(async move {
@@ -71,21 +122,52 @@ async fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-async fn setup_type_map(client: &mut Client) -> Result<(), Box<dyn Error>> {
+async fn setup_type_map(client: &mut Client, config: &Config) -> Result<(), Box<dyn Error>> {
     // These extra scopes are added to drop the `GuardLock` on `data` as soon
     // as possible. This not strictly necessary here, but in general best
     // pratice to hold the locks as shortly as possible.
     {
-        let siege_client: siege_api::client::Client =
-            Auth::from_environment().connect().await.unwrap().into();
+        let auth = Auth::new(
+            config.ubisoft.email.clone(),
+            config.ubisoft.password.clone(),
+        );
+        let siege_client: siege_api::client::Client = auth
+            .connect()
+            .await
+            .map_err(|err| format!("Failed to connect to Ubisoft: {err:?}"))?
+            .into();
         let mut data = client.data.write().await;
         data.insert::<SiegeApi>(Arc::new(siege_client));
     }
     {
-        let lookup = PlayerLookupImpl::load(".players.json")?;
+        let lookup = PlayerLookupImpl::load(config.storage.file(".players.json"))?;
         let mut data = client.data.write().await;
         data.insert::<SiegePlayerLookup>(Arc::new(RwLock::new(lookup)));
     }
+    {
+        // Channels selected with the `notifications` command take precedence.
+        let channels = ChannelLookupImpl::load(config.storage.file(".notifications.json"))?
+            .with_fallback(config.notifications.channels.clone());
+        let mut data = client.data.write().await;
+        data.insert::<NotificationChannels>(Arc::new(RwLock::new(channels)));
+    }
+    {
+        let settings = SettingsLookupImpl::load(config.storage.file(".settings.json"))?;
+        let mut data = client.data.write().await;
+        data.insert::<GuildSettings>(Arc::new(RwLock::new(settings)));
+    }
+    {
+        let sessions = PlaySessionLookupImpl::load(config.storage.file(".sessions.json"))?;
+        let mut data = client.data.write().await;
+        data.insert::<PlaySessions>(Arc::new(RwLock::new(sessions)));
+    }
+    {
+        let sessions = PaginationSessions::new(config.cache.pagination_ttl());
+        let mut data = client.data.write().await;
+        data.insert::<Paginations>(Arc::new(RwLock::new(sessions)));
+        data.insert::<Defaults>(config.defaults);
+        data.insert::<RankRolesConfig>(config.rank_roles.clone());
+    }
 
     Ok(())
 }
@@ -0,0 +1,184 @@
+//! Recommendations of the ranked maps a team should ban and pick, based on the
+//! round win rates of the players on each map.
+
+use std::collections::HashMap;
+
+use siege_api::{
+    data::map::Playlist,
+    maps::{get_map_details, Map},
+    models::{ratio, MapStatistics},
+};
+
+/// Number of rounds worth of the team's overall win rate added to every map.
+/// Maps with few rounds are pulled towards the overall win rate, so a lucky
+/// streak on a rarely played map does not outweigh a large sample.
+const PRIOR_ROUNDS: f64 = 30.0;
+
+/// Round statistics for a single map, summed over all players in the team.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapScore {
+    pub map: Map,
+    pub rounds_won: u64,
+    pub rounds_played: u64,
+    pub players: usize,
+    pub score: f64,
+}
+
+impl MapScore {
+    /// The raw round win rate of the team on this map. Undefined without any
+    /// rounds.
+    pub fn win_rate(&self) -> Option<f64> {
+        ratio(self.rounds_won, self.rounds_played)
+    }
+}
+
+/// The maps a team should ban and pick, from worst and best respectively.
+#[derive(Debug, Default)]
+pub struct MapRecommendations {
+    pub bans: Vec<MapScore>,
+    pub picks: Vec<MapScore>,
+}
+
+/// Sum the round statistics per map for ranked maps.
+pub fn aggregate<'a>(maps: impl IntoIterator<Item = &'a MapStatistics>) -> HashMap<Map, MapScore> {
+    maps.into_iter()
+        .filter(|x| get_map_details(*x.name()).in_playlist(Playlist::Ranked))
+        .fold(HashMap::new(), |mut acc, x| {
+            let entry = acc.entry(*x.name()).or_insert_with(|| MapScore {
+                map: *x.name(),
+                rounds_won: 0,
+                rounds_played: 0,
+                players: 0,
+                score: 0.0,
+            });
+            entry.rounds_won += x.statistics().rounds_won();
+            entry.rounds_played += x.statistics().rounds_played();
+            entry.players += 1;
+            acc
+        })
+}
+
+/// Score every map with enough rounds played, weighting the win rate by the
+/// sample size, and recommend the worst maps as bans and the best as picks.
+pub fn recommend(
+    maps: HashMap<Map, MapScore>,
+    minimum_rounds: u64,
+    count: usize,
+) -> MapRecommendations {
+    let (won, played) = maps.values().fold((0, 0), |(won, played), x| {
+        (won + x.rounds_won, played + x.rounds_played)
+    });
+    // Without any rounds every map scores the same, whatever the prior is.
+    let prior = ratio(won, played).unwrap_or_default();
+
+    let mut scores = maps
+        .into_values()
+        .filter(|x| x.rounds_played >= minimum_rounds)
+        .map(|mut x| {
+            x.score = (x.rounds_won as f64 + PRIOR_ROUNDS * prior)
+                / (x.rounds_played as f64 + PRIOR_ROUNDS);
+            x
+        })
+        .collect::<Vec<_>>();
+    scores.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .expect("should always be valid")
+            .then_with(|| a.map.to_string().cmp(&b.map.to_string()))
+    });
+
+    // Never recommend the same map as both a pick and a ban.
+    let picks_count = count.min(scores.len() / 2);
+    let bans = scores
+        .split_off(picks_count)
+        .into_iter()
+        .rev()
+        .take(count)
+        .collect();
+
+    MapRecommendations {
+        bans,
+        picks: scores,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use siege_api::models::{GameModeOrAll, SideOrAll, StatisticResponse};
+
+    use super::*;
+
+    fn load_maps() -> StatisticResponse {
+        let content = std::fs::read_to_string("../samples/maps.json").unwrap();
+        serde_json::from_str(content.as_str()).unwrap()
+    }
+
+    fn score(map: Map, rounds_won: u64, rounds_played: u64) -> (Map, MapScore) {
+        (
+            map,
+            MapScore {
+                map,
+                rounds_won,
+                rounds_played,
+                players: 1,
+                score: 0.0,
+            },
+        )
+    }
+
+    #[test]
+    fn aggregate_ranked_maps() {
+        let stats = load_maps();
+        let maps = stats.get_maps(GameModeOrAll::All, SideOrAll::All);
+        let single = aggregate(maps.iter().copied());
+        let double = aggregate(maps.iter().chain(maps.iter()).copied());
+
+        assert!(!single.contains_key(&Map::Yacht));
+        assert!(!single.contains_key(&Map::Favela));
+
+        let kanal = &single[&Map::Kanal];
+        assert_eq!(kanal.rounds_won, 47);
+        assert_eq!(kanal.rounds_played, 94);
+        assert_eq!(kanal.players, 1);
+        assert_eq!(kanal.win_rate(), Some(0.5));
+
+        let kanal = &double[&Map::Kanal];
+        assert_eq!(kanal.rounds_played, 188);
+        assert_eq!(kanal.players, 2);
+    }
+
+    #[test]
+    fn recommend_weights_by_sample_size() {
+        let maps = HashMap::from([
+            score(Map::Bank, 50, 100),
+            score(Map::Border, 70, 100),
+            score(Map::Chalet, 30, 100),
+            // A perfect win rate on a few rounds should not be the best pick.
+            score(Map::KafeDostoyevsky, 5, 5),
+            score(Map::Villa, 15, 20),
+            score(Map::Oregon, 1, 2),
+        ]);
+
+        let recommendations = recommend(maps, 5, 2);
+        let names = |x: &[MapScore]| x.iter().map(|x| x.map).collect::<Vec<_>>();
+
+        assert_eq!(names(&recommendations.picks), vec![Map::Border, Map::Villa]);
+        assert_eq!(names(&recommendations.bans), vec![Map::Chalet, Map::Bank]);
+        assert!(recommendations.picks[0].score < 0.7);
+    }
+
+    #[test]
+    fn recommend_never_picks_and_bans_same_map() {
+        let maps = HashMap::from([score(Map::Bank, 50, 100), score(Map::Border, 70, 100)]);
+        let recommendations = recommend(maps, 0, 3);
+
+        assert_eq!(recommendations.picks.len(), 1);
+        assert_eq!(recommendations.picks[0].map, Map::Border);
+        assert_eq!(recommendations.bans.len(), 1);
+        assert_eq!(recommendations.bans[0].map, Map::Bank);
+
+        let recommendations = recommend(HashMap::from([score(Map::Bank, 1, 2)]), 10, 3);
+        assert!(recommendations.picks.is_empty());
+        assert!(recommendations.bans.is_empty());
+    }
+}
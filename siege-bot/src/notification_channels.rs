@@ -0,0 +1,194 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::write,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use serenity::{
+    model::prelude::{ChannelId, GuildId},
+    prelude::{RwLock, TypeMapKey},
+};
+
+use crate::config::StorageConfig;
+
+pub struct NotificationChannels;
+impl TypeMapKey for NotificationChannels {
+    type Value = Arc<RwLock<dyn ChannelLookup>>;
+}
+
+/// Keeps track of which channel each guild wants to receive notifications in.
+#[cfg_attr(test, mockall::automock)]
+pub trait ChannelLookup: Send + Sync {
+    fn get(&self, guild_id: &GuildId) -> Option<ChannelId>;
+    fn all(&self) -> Vec<(GuildId, ChannelId)>;
+    fn insert(&mut self, guild_id: &GuildId, channel_id: ChannelId) -> Result<(), std::io::Error>;
+    fn remove(&mut self, guild_id: &GuildId) -> Result<(), std::io::Error>;
+}
+
+#[derive(Debug)]
+pub struct ChannelLookupImpl {
+    path: PathBuf,
+    /// Channels selected with the `notifications` command. `None` if the
+    /// guild turned off the notifications from the configuration. Files
+    /// stored before, with only channels, are read the same way.
+    channels: HashMap<GuildId, Option<ChannelId>>,
+    /// Channels from the configuration, which are never stored.
+    fallback: HashMap<GuildId, ChannelId>,
+}
+
+impl ChannelLookup for ChannelLookupImpl {
+    /// Get the notification channel for a guild.
+    fn get(&self, guild_id: &GuildId) -> Option<ChannelId> {
+        match self.channels.get(guild_id) {
+            Some(channel_id) => *channel_id,
+            None => self.fallback.get(guild_id).copied(),
+        }
+    }
+
+    /// Get all the registered notification channels.
+    fn all(&self) -> Vec<(GuildId, ChannelId)> {
+        self.fallback
+            .keys()
+            .chain(self.channels.keys())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .filter_map(|guild_id| self.get(guild_id).map(|c| (*guild_id, c)))
+            .collect()
+    }
+
+    /// Set the notification channel for a guild. Only one channel is
+    /// supported for each guild, so this will overwrite any existing channel.
+    fn insert(&mut self, guild_id: &GuildId, channel_id: ChannelId) -> Result<(), std::io::Error> {
+        self.channels.insert(*guild_id, Some(channel_id));
+        self.persist()
+    }
+
+    /// Stop sending notifications to the given guild, including to the
+    /// channel from the configuration.
+    fn remove(&mut self, guild_id: &GuildId) -> Result<(), std::io::Error> {
+        match self.fallback.contains_key(guild_id) {
+            true => self.channels.insert(*guild_id, None),
+            false => self.channels.remove(guild_id),
+        };
+        self.persist()
+    }
+}
+
+impl ChannelLookupImpl {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let channels = match StorageConfig::read(path) {
+            Ok(content) => serde_json::from_str(content.as_str())?,
+            Err(err) => {
+                tracing::warn!(
+                    "Failed to read notification channels. Creating default. Error: {err:?}"
+                );
+                HashMap::default()
+            }
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            channels,
+            fallback: HashMap::default(),
+        })
+    }
+
+    /// Use the channels from the configuration for guilds which have not
+    /// selected a channel with the `notifications` command.
+    pub fn with_fallback(mut self, fallback: HashMap<GuildId, ChannelId>) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    fn persist(&self) -> Result<(), std::io::Error> {
+        let content =
+            serde_json::to_string_pretty(&self.channels).expect("should always be serializeable");
+        write(&self.path, content)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn load_from_disk_without_existing_file() {
+        assert!(ChannelLookupImpl::load("not existing file").is_ok());
+    }
+
+    #[test]
+    fn insert_load_and_remove() {
+        let guild_id = GuildId::from(1290213);
+        let channel_id = ChannelId::from(4711);
+
+        let file = NamedTempFile::new().unwrap();
+        let filename = file.path().to_str().unwrap().to_string();
+        std::fs::write(&filename, "{}").unwrap();
+
+        let mut lookup = ChannelLookupImpl::load(filename.as_str()).unwrap();
+        lookup
+            .insert(&guild_id, channel_id)
+            .expect("should be able to persist");
+
+        // Act - Load from the exsiting file
+        let mut lookup = ChannelLookupImpl::load(filename.as_str()).unwrap();
+        assert_eq!(lookup.get(&guild_id), Some(channel_id));
+        assert_eq!(lookup.all(), vec![(guild_id, channel_id)]);
+
+        lookup.remove(&guild_id).expect("should be able to persist");
+        let lookup = ChannelLookupImpl::load(filename.as_str()).unwrap();
+        assert_eq!(lookup.get(&guild_id), None);
+    }
+
+    #[test]
+    fn load_file_with_only_channels() {
+        let file = NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), r#"{ "1290213": 4711 }"#).unwrap();
+
+        let lookup = ChannelLookupImpl::load(file.path()).unwrap();
+        assert_eq!(
+            lookup.all(),
+            vec![(GuildId::from(1290213), ChannelId::from(4711))]
+        );
+    }
+
+    #[test]
+    fn fallback_is_not_stored() {
+        let guild_id = GuildId::from(1290213);
+        let fallback = HashMap::from([(guild_id, ChannelId::from(1))]);
+
+        let file = NamedTempFile::new().unwrap();
+        let filename = file.path().to_str().unwrap().to_string();
+        std::fs::write(&filename, "{}").unwrap();
+
+        let mut lookup = ChannelLookupImpl::load(filename.as_str())
+            .unwrap()
+            .with_fallback(fallback.clone());
+        assert_eq!(lookup.get(&guild_id), Some(ChannelId::from(1)));
+        assert_eq!(lookup.all(), vec![(guild_id, ChannelId::from(1))]);
+
+        // A selected channel takes precedence over the configuration.
+        lookup
+            .insert(&guild_id, ChannelId::from(2))
+            .expect("should be able to persist");
+        assert_eq!(lookup.get(&guild_id), Some(ChannelId::from(2)));
+
+        // Turning notifications off also applies to the configured channel.
+        lookup.remove(&guild_id).expect("should be able to persist");
+        let lookup = ChannelLookupImpl::load(filename.as_str())
+            .unwrap()
+            .with_fallback(fallback.clone());
+        assert_eq!(lookup.get(&guild_id), None);
+        assert!(lookup.all().is_empty());
+
+        // The configured channel is used again once the file is removed.
+        let lookup = ChannelLookupImpl::load("not existing file")
+            .unwrap()
+            .with_fallback(fallback);
+        assert_eq!(lookup.get(&guild_id), Some(ChannelId::from(1)));
+    }
+}
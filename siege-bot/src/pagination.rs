@@ -0,0 +1,586 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use serenity::{
+    builder::{CreateComponents, CreateEmbed},
+    model::prelude::{component::ButtonStyle, UserId},
+    prelude::{RwLock, TypeMapKey},
+};
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
+
+use crate::{
+    commands::{
+        context::DiscordContext,
+        discord_app_command::{Attachment, DiscordAppCmd, DiscordComponentInteraction},
+        CmdResult,
+    },
+    formatting::FormatEmbedded,
+};
+
+/// Number of rows on each page of a list. Keeps every field below Discord's
+/// limit of 1024 characters.
+pub const ROWS_PER_PAGE: usize = 15;
+
+/// How long the buttons of a paginated message work after they were last used,
+/// unless configured otherwise.
+const SESSION_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Prefix of the custom ID of the pagination buttons, which are formatted as
+/// `page:<session>:<action>`.
+const CUSTOM_ID_PREFIX: &str = "page";
+
+pub struct Paginations;
+impl TypeMapKey for Paginations {
+    type Value = Arc<RwLock<PaginationSessions>>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumString, EnumIter)]
+#[strum(serialize_all = "lowercase")]
+enum PageAction {
+    First,
+    Previous,
+    Next,
+    Last,
+}
+
+impl PageAction {
+    fn label(&self) -> &'static str {
+        match self {
+            PageAction::First => "⏮ First",
+            PageAction::Previous => "◀ Previous",
+            PageAction::Next => "Next ▶",
+            PageAction::Last => "Last ⏭",
+        }
+    }
+
+    /// Get the page the action leads to from the current page.
+    fn apply(&self, current: usize, pages: usize) -> usize {
+        match self {
+            PageAction::First => 0,
+            PageAction::Previous => current.saturating_sub(1),
+            PageAction::Next => (current + 1).min(pages - 1),
+            PageAction::Last => pages - 1,
+        }
+    }
+
+    /// Check if the action would not change the page.
+    fn is_disabled(&self, current: usize, pages: usize) -> bool {
+        self.apply(current, pages) == current
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum NavigationError {
+    /// The session timed out, or was lost when the bot restarted.
+    Expired,
+    /// Only the user who ran the command can change pages.
+    NotOwner,
+}
+
+#[derive(Debug)]
+struct Session {
+    owner: UserId,
+    pages: Vec<CreateEmbed>,
+    current: usize,
+    last_used: Instant,
+}
+
+/// The paginated messages which can still be navigated.
+#[derive(Debug)]
+pub struct PaginationSessions {
+    sessions: HashMap<u64, Session>,
+    timeout: Duration,
+}
+
+impl Default for PaginationSessions {
+    fn default() -> Self {
+        Self::new(SESSION_TIMEOUT)
+    }
+}
+
+impl PaginationSessions {
+    /// Create sessions which expire `timeout` after they were last used.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            sessions: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// Start a session for the pages and return its ID.
+    fn start(&mut self, owner: UserId, pages: Vec<CreateEmbed>, now: Instant) -> u64 {
+        let timeout = self.timeout;
+        self.sessions
+            .retain(|_, session| now.duration_since(session.last_used) < timeout);
+
+        let id = rand::random();
+        self.sessions.insert(
+            id,
+            Session {
+                owner,
+                pages,
+                current: 0,
+                last_used: now,
+            },
+        );
+        id
+    }
+
+    /// Move to another page, and get the new page with its buttons.
+    fn navigate(
+        &mut self,
+        id: u64,
+        user_id: UserId,
+        action: PageAction,
+        now: Instant,
+    ) -> Result<(CreateEmbed, CreateComponents), NavigationError> {
+        let session = match self.sessions.get_mut(&id) {
+            Some(session) if now.duration_since(session.last_used) < self.timeout => session,
+            Some(_) => {
+                self.sessions.remove(&id);
+                return Err(NavigationError::Expired);
+            }
+            None => return Err(NavigationError::Expired),
+        };
+        if session.owner != user_id {
+            return Err(NavigationError::NotOwner);
+        }
+
+        session.current = action.apply(session.current, session.pages.len());
+        session.last_used = now;
+
+        Ok((
+            session.pages[session.current].clone(),
+            buttons(id, session.current, session.pages.len()),
+        ))
+    }
+}
+
+/// Split the items into pages of `ROWS_PER_PAGE` rows, each formatted by the
+/// list formatter on top of a copy of `base`.
+pub fn paginate<T>(base: &CreateEmbed, items: &[T]) -> Vec<CreateEmbed>
+where
+    T: Clone,
+    CreateEmbed: for<'a> FormatEmbedded<'a, Vec<T>>,
+{
+    if items.is_empty() {
+        return vec![base.clone().format(&Vec::new()).to_owned()];
+    }
+
+    let total = (items.len() + ROWS_PER_PAGE - 1) / ROWS_PER_PAGE;
+    items
+        .chunks(ROWS_PER_PAGE)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let mut page = base.clone();
+            page.format(&chunk.to_vec());
+            if total > 1 {
+                page.footer(|footer| footer.text(format!("Page {} of {total}", i + 1)));
+            }
+            page
+        })
+        .collect()
+}
+
+fn buttons(id: u64, current: usize, pages: usize) -> CreateComponents {
+    let mut components = CreateComponents::default();
+    components.create_action_row(|row| {
+        PageAction::iter().for_each(|action| {
+            row.create_button(|button| {
+                button
+                    .custom_id(format!("{CUSTOM_ID_PREFIX}:{id}:{action}"))
+                    .label(action.label())
+                    .style(ButtonStyle::Secondary)
+                    .disabled(action.is_disabled(current, pages))
+            });
+        });
+        row
+    });
+    components
+}
+
+fn parse_custom_id(custom_id: &str) -> Option<(u64, PageAction)> {
+    let mut parts = custom_id.split(':');
+    if parts.next() != Some(CUSTOM_ID_PREFIX) {
+        return None;
+    }
+    let id = parts.next()?.parse().ok()?;
+    let action = parts.next()?.parse().ok()?;
+
+    parts.next().is_none().then_some((id, action))
+}
+
+/// Send the pages as a response to the command. With more than one page,
+/// buttons to change the page are added below the embed. The attachment, e.g.
+/// a chart, stays on the message when the page changes.
+pub async fn send_pages<Ctx, Cmd>(
+    ctx: &Ctx,
+    command: &Cmd,
+    mut pages: Vec<CreateEmbed>,
+    attachment: Option<Attachment>,
+) -> CmdResult
+where
+    Ctx: DiscordContext + Send + Sync,
+    Cmd: DiscordAppCmd,
+{
+    if pages.len() <= 1 {
+        let page = pages.pop().unwrap_or_default();
+        return match attachment {
+            Some(attachment) => {
+                command
+                    .send_embedded_with_attachment(ctx.http(), page, attachment)
+                    .await
+            }
+            None => command.send_embedded(ctx.http(), page).await,
+        };
+    }
+
+    let first = pages[0].clone();
+    let total = pages.len();
+    let id = {
+        let data = ctx.data().read().await;
+        let mut sessions = data
+            .get::<Paginations>()
+            .expect("always registered")
+            .write()
+            .await;
+        sessions.start(command.user_id(), pages, Instant::now())
+    };
+
+    command
+        .send_embedded_with_components(ctx.http(), first, buttons(id, 0, total), attachment)
+        .await
+}
+
+/// Change the page of a paginated message when one of its buttons is used.
+/// Returns `None` if the component is not a pagination button.
+pub async fn handle_component<Ctx, Component>(ctx: &Ctx, component: &Component) -> Option<CmdResult>
+where
+    Ctx: DiscordContext + Send + Sync,
+    Component: DiscordComponentInteraction,
+{
+    let (id, action) = parse_custom_id(component.custom_id().as_str())?;
+
+    let result = {
+        let data = ctx.data().read().await;
+        let mut sessions = data
+            .get::<Paginations>()
+            .expect("always registered")
+            .write()
+            .await;
+        sessions.navigate(id, component.user_id(), action, Instant::now())
+    };
+
+    Some(match result {
+        Ok((embed, components)) => {
+            component
+                .update_message(ctx.http(), embed, components)
+                .await
+        }
+        Err(NavigationError::Expired) => {
+            component
+                .send_ephemeral_text(
+                    ctx.http(),
+                    "This list has expired. Run the command again to browse it",
+                )
+                .await
+        }
+        Err(NavigationError::NotOwner) => {
+            component
+                .send_ephemeral_text(
+                    ctx.http(),
+                    "Only the user who ran the command can change the page",
+                )
+                .await
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use mockall::predicate::*;
+    use serenity::prelude::TypeMap;
+    use siege_api::models::{GameModeOrAll, OperatorStatistics, SideOrAll, StatisticResponse};
+
+    use crate::commands::{
+        context::MockDiscordContext,
+        discord_app_command::{MockDiscordAppCmd, MockDiscordComponentInteraction},
+    };
+
+    use super::*;
+
+    fn create_pages(count: usize) -> Vec<CreateEmbed> {
+        (0..count)
+            .map(|i| CreateEmbed::default().title(i.to_string()).to_owned())
+            .collect()
+    }
+
+    fn disabled_buttons(components: &CreateComponents) -> Vec<bool> {
+        components.0[0]
+            .get("components")
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|x| x.get("disabled").unwrap().as_bool().unwrap())
+            .collect()
+    }
+
+    async fn create_context(sessions: PaginationSessions) -> MockDiscordContext {
+        let mut ctx = MockDiscordContext::new();
+        ctx.expect_http().return_const(None);
+        let data = Arc::new(RwLock::new(TypeMap::default()));
+        data.write()
+            .await
+            .insert::<Paginations>(Arc::new(RwLock::new(sessions)));
+        ctx.expect_data().return_const(data);
+        ctx
+    }
+
+    #[test]
+    fn paginate_operators() {
+        let content = std::fs::read_to_string("../samples/operators.json").unwrap();
+        let stats: StatisticResponse = serde_json::from_str(content.as_str()).unwrap();
+        let operators = stats.get_operators(GameModeOrAll::All, SideOrAll::All);
+        assert!(operators.len() > ROWS_PER_PAGE);
+
+        let pages = paginate(&CreateEmbed::default(), &operators);
+
+        assert_eq!(
+            pages.len(),
+            (operators.len() + ROWS_PER_PAGE - 1) / ROWS_PER_PAGE
+        );
+        assert_eq!(
+            pages[0].0.get("footer").unwrap().get("text").unwrap(),
+            &format!("Page 1 of {}", pages.len())
+        );
+        pages.iter().for_each(|page| {
+            page.0
+                .get("fields")
+                .unwrap()
+                .as_array()
+                .unwrap()
+                .iter()
+                .for_each(|field| {
+                    assert!(field.get("value").unwrap().as_str().unwrap().len() <= 1024);
+                });
+        });
+    }
+
+    #[test]
+    fn paginate_empty_list() {
+        let pages = paginate::<&OperatorStatistics>(&CreateEmbed::default(), &[]);
+
+        assert_eq!(pages.len(), 1);
+        assert!(!pages[0].0.contains_key("footer"));
+    }
+
+    #[test]
+    fn parse_button_ids() {
+        assert_eq!(
+            parse_custom_id("page:42:next"),
+            Some((42, PageAction::Next))
+        );
+        assert_eq!(
+            parse_custom_id("page:42:first"),
+            Some((42, PageAction::First))
+        );
+        assert_eq!(parse_custom_id("page:42:sideways"), None);
+        assert_eq!(parse_custom_id("page:abc:next"), None);
+        assert_eq!(parse_custom_id("other:42:next"), None);
+        assert_eq!(parse_custom_id("page:42:next:1"), None);
+    }
+
+    #[test]
+    fn navigate_between_pages() {
+        let now = Instant::now();
+        let mut sessions = PaginationSessions::default();
+        let id = sessions.start(UserId(1), create_pages(3), now);
+
+        let (page, components) = sessions
+            .navigate(id, UserId(1), PageAction::Next, now)
+            .unwrap();
+        assert_eq!(page.0.get("title").unwrap(), "1");
+        assert_eq!(
+            disabled_buttons(&components),
+            vec![false, false, false, false]
+        );
+
+        let (page, components) = sessions
+            .navigate(id, UserId(1), PageAction::Last, now)
+            .unwrap();
+        assert_eq!(page.0.get("title").unwrap(), "2");
+        assert_eq!(
+            disabled_buttons(&components),
+            vec![false, false, true, true]
+        );
+
+        let (page, _) = sessions
+            .navigate(id, UserId(1), PageAction::Next, now)
+            .unwrap();
+        assert_eq!(page.0.get("title").unwrap(), "2");
+
+        let (page, components) = sessions
+            .navigate(id, UserId(1), PageAction::First, now)
+            .unwrap();
+        assert_eq!(page.0.get("title").unwrap(), "0");
+        assert_eq!(
+            disabled_buttons(&components),
+            vec![true, true, false, false]
+        );
+    }
+
+    #[test]
+    fn navigate_by_other_user() {
+        let now = Instant::now();
+        let mut sessions = PaginationSessions::default();
+        let id = sessions.start(UserId(1), create_pages(2), now);
+
+        assert_eq!(
+            sessions
+                .navigate(id, UserId(2), PageAction::Next, now)
+                .unwrap_err(),
+            NavigationError::NotOwner
+        );
+    }
+
+    #[test]
+    fn sessions_expire() {
+        let now = Instant::now();
+        let mut sessions = PaginationSessions::default();
+        let id = sessions.start(UserId(1), create_pages(2), now);
+
+        assert!(sessions
+            .navigate(id, UserId(1), PageAction::Next, now + SESSION_TIMEOUT / 2)
+            .is_ok());
+        assert_eq!(
+            sessions
+                .navigate(id, UserId(1), PageAction::Next, now + SESSION_TIMEOUT * 2)
+                .unwrap_err(),
+            NavigationError::Expired
+        );
+        assert!(sessions.sessions.is_empty());
+
+        // Expired sessions are removed when a new session is started.
+        sessions.start(UserId(1), create_pages(2), now);
+        sessions.start(UserId(1), create_pages(2), now + SESSION_TIMEOUT * 2);
+        assert_eq!(sessions.sessions.len(), 1);
+    }
+
+    #[test]
+    fn sessions_expire_after_configured_timeout() {
+        let now = Instant::now();
+        let mut sessions = PaginationSessions::new(Duration::from_secs(30));
+        let id = sessions.start(UserId(1), create_pages(2), now);
+
+        assert_eq!(
+            sessions
+                .navigate(
+                    id,
+                    UserId(1),
+                    PageAction::Next,
+                    now + Duration::from_secs(60)
+                )
+                .unwrap_err(),
+            NavigationError::Expired
+        );
+    }
+
+    #[tokio::test]
+    async fn send_single_page() {
+        let ctx = create_context(PaginationSessions::default()).await;
+
+        let mut command = MockDiscordAppCmd::new();
+        command
+            .expect_send_embedded()
+            .once()
+            .returning(|_, _| Ok(()));
+
+        assert!(send_pages(&ctx, &command, create_pages(1), None)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn send_and_navigate_pages() {
+        let ctx = create_context(PaginationSessions::default()).await;
+
+        let mut command = MockDiscordAppCmd::new();
+        command.expect_user_id().return_const(UserId(1));
+        command
+            .expect_send_embedded_with_components()
+            .once()
+            .withf(|_, embed, components, attachment| {
+                embed.0.get("title").unwrap() == "0"
+                    && disabled_buttons(components) == vec![true, true, false, false]
+                    && attachment.is_none()
+            })
+            .returning(|_, _, _, _| Ok(()));
+
+        assert!(send_pages(&ctx, &command, create_pages(2), None)
+            .await
+            .is_ok());
+
+        let id = *ctx
+            .data()
+            .read()
+            .await
+            .get::<Paginations>()
+            .unwrap()
+            .read()
+            .await
+            .sessions
+            .keys()
+            .next()
+            .unwrap();
+
+        let mut component = MockDiscordComponentInteraction::new();
+        component
+            .expect_custom_id()
+            .return_const(format!("page:{id}:next"));
+        component.expect_user_id().return_const(UserId(1));
+        component
+            .expect_update_message()
+            .once()
+            .withf(|_, embed, _| embed.0.get("title").unwrap() == "1")
+            .returning(|_, _, _| Ok(()));
+
+        assert!(handle_component(&ctx, &component).await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn handle_expired_session() {
+        let ctx = create_context(PaginationSessions::default()).await;
+
+        let mut component = MockDiscordComponentInteraction::new();
+        component
+            .expect_custom_id()
+            .return_const("page:1:next".to_string());
+        component.expect_user_id().return_const(UserId(1));
+        component
+            .expect_send_ephemeral_text()
+            .once()
+            .with(
+                always(),
+                eq("This list has expired. Run the command again to browse it"),
+            )
+            .returning(|_, _| Ok(()));
+
+        assert!(handle_component(&ctx, &component).await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn ignore_other_components() {
+        let ctx = MockDiscordContext::new();
+
+        let mut component = MockDiscordComponentInteraction::new();
+        component
+            .expect_custom_id()
+            .return_const("other".to_string());
+
+        assert!(handle_component(&ctx, &component).await.is_none());
+    }
+}
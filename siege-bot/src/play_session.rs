@@ -0,0 +1,329 @@
+//! Play sessions started and ended by members with `/session`. Ubisoft only
+//! returns the totals of the season, so a snapshot is taken when the session
+//! starts and compared with the totals when it ends.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{read_to_string, write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize, Serializer};
+use serenity::{
+    model::prelude::UserId,
+    prelude::{RwLock, TypeMapKey},
+};
+use siege_api::models::{
+    ratio, GameMode, GameModeOrAll, PlatformFamily, RankedV2Response, SeasonStatistics, SideOrAll,
+    StatisticResponse,
+};
+use strum::IntoEnumIterator;
+use uuid::Uuid;
+
+/// Number of operators listed in the summary of a session.
+const LISTED_OPERATORS: usize = 5;
+
+pub struct PlaySessions;
+impl TypeMapKey for PlaySessions {
+    type Value = Arc<RwLock<dyn PlaySessionLookup>>;
+}
+
+/// Keeps track of the running session of each user.
+#[cfg_attr(test, mockall::automock)]
+pub trait PlaySessionLookup: Send + Sync {
+    fn get(&self, user_id: &UserId) -> Option<SessionSnapshot>;
+    fn insert(&mut self, user_id: &UserId, snapshot: SessionSnapshot)
+        -> Result<(), std::io::Error>;
+    fn remove(&mut self, user_id: &UserId) -> Result<(), std::io::Error>;
+}
+
+/// The totals of a player at a point in time, summed over the game modes of
+/// the current season.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    pub taken: DateTime<Utc>,
+    pub player_id: Uuid,
+    #[serde(serialize_with = "serialize_display")]
+    pub platform: PlatformFamily,
+    pub wins: u64,
+    pub losses: u64,
+    pub kills: u64,
+    pub deaths: u64,
+    /// `None` if the player has no ranked profile on the platform.
+    pub rank_points: Option<u64>,
+    /// Rounds played on each operator.
+    pub operators: HashMap<String, u64>,
+}
+
+impl SessionSnapshot {
+    pub fn new(
+        taken: DateTime<Utc>,
+        player_id: Uuid,
+        platform: PlatformFamily,
+        profiles: &RankedV2Response,
+        operators: &StatisticResponse,
+    ) -> Self {
+        let boards = GameMode::iter()
+            .filter(|game_mode| *game_mode != GameMode::Unknown)
+            .filter_map(|game_mode| profiles.get_board(platform, game_mode))
+            .map(|board| *board.season_statistics())
+            .collect::<Vec<_>>();
+        let total = |value: fn(&SeasonStatistics) -> u64| -> u64 { boards.iter().map(value).sum() };
+
+        Self {
+            taken,
+            player_id,
+            platform,
+            wins: total(|x| *x.match_outcomes().wins()),
+            losses: total(|x| *x.match_outcomes().losses()),
+            kills: total(|x| *x.kills()),
+            deaths: total(|x| *x.deaths()),
+            rank_points: profiles
+                .get_board(platform, GameMode::Ranked)
+                .map(|board| *board.profile().rank_points()),
+            operators: operators
+                .get_operators(GameModeOrAll::All, SideOrAll::All)
+                .iter()
+                .map(|x| (x.name().to_string(), *x.statistics().rounds_played()))
+                .collect(),
+        }
+    }
+
+    /// Compare with the snapshot taken at the end of the session. Totals
+    /// which went down, e.g. when a new season started during the session,
+    /// count as zero.
+    pub fn summary(&self, end: &SessionSnapshot) -> SessionSummary {
+        let mut operators = end
+            .operators
+            .iter()
+            .map(|(name, rounds)| {
+                let before = self.operators.get(name).copied().unwrap_or_default();
+                (name.clone(), rounds.saturating_sub(before))
+            })
+            .filter(|(_, rounds)| *rounds > 0)
+            .collect::<Vec<_>>();
+        operators.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        operators.truncate(LISTED_OPERATORS);
+
+        SessionSummary {
+            duration: end.taken - self.taken,
+            wins: end.wins.saturating_sub(self.wins),
+            losses: end.losses.saturating_sub(self.losses),
+            kills: end.kills.saturating_sub(self.kills),
+            deaths: end.deaths.saturating_sub(self.deaths),
+            rank_points: self
+                .rank_points
+                .zip(end.rank_points)
+                .map(|(before, after)| after as i64 - before as i64),
+            operators,
+        }
+    }
+}
+
+/// What a player did between the start and end of a session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionSummary {
+    pub duration: Duration,
+    pub wins: u64,
+    pub losses: u64,
+    pub kills: u64,
+    pub deaths: u64,
+    /// Change of the rank points, if the player has a ranked profile.
+    pub rank_points: Option<i64>,
+    /// The operators with the most rounds played in the session.
+    pub operators: Vec<(String, u64)>,
+}
+
+impl SessionSummary {
+    pub fn matches(&self) -> u64 {
+        self.wins + self.losses
+    }
+
+    /// Calculate the kill/death ratio. Undefined without any deaths.
+    pub fn kd(&self) -> Option<f64> {
+        ratio(self.kills, self.deaths)
+    }
+
+    /// Calculate the win rate of matches. Undefined without any matches.
+    pub fn win_rate(&self) -> Option<f64> {
+        ratio(self.wins, self.matches())
+    }
+}
+
+/// Store the enums from the Siege API, which are not serializable, by name.
+/// They are deserialized from their name by the Siege API.
+fn serialize_display<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Display,
+{
+    serializer.collect_str(value)
+}
+
+#[derive(Debug)]
+pub struct PlaySessionLookupImpl {
+    path: PathBuf,
+    sessions: HashMap<UserId, SessionSnapshot>,
+}
+
+impl PlaySessionLookup for PlaySessionLookupImpl {
+    /// Get the snapshot taken when the user started their session, if running.
+    fn get(&self, user_id: &UserId) -> Option<SessionSnapshot> {
+        self.sessions.get(user_id).cloned()
+    }
+
+    /// Start a session for the user, replacing any running session.
+    fn insert(
+        &mut self,
+        user_id: &UserId,
+        snapshot: SessionSnapshot,
+    ) -> Result<(), std::io::Error> {
+        self.sessions.insert(*user_id, snapshot);
+        self.persist()
+    }
+
+    /// End the session of the user.
+    fn remove(&mut self, user_id: &UserId) -> Result<(), std::io::Error> {
+        self.sessions.remove(user_id);
+        self.persist()
+    }
+}
+
+impl PlaySessionLookupImpl {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let sessions = match read_to_string(path) {
+            Ok(content) => serde_json::from_str(content.as_str())?,
+            Err(err) => {
+                tracing::warn!("Failed to read play sessions. Creating default. Error: {err:?}");
+                HashMap::default()
+            }
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            sessions,
+        })
+    }
+
+    fn persist(&self) -> Result<(), std::io::Error> {
+        let content =
+            serde_json::to_string_pretty(&self.sessions).expect("should always be serializeable");
+        write(&self.path, content)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    use tempfile::NamedTempFile;
+
+    pub(crate) fn snapshot(wins: u64, kills: u64, rank_points: Option<u64>) -> SessionSnapshot {
+        SessionSnapshot {
+            taken: DateTime::parse_from_rfc3339("2023-05-01T18:00:00Z")
+                .unwrap()
+                .into(),
+            player_id: Uuid::nil(),
+            platform: PlatformFamily::Pc,
+            wins,
+            losses: 10,
+            kills,
+            deaths: 100,
+            rank_points,
+            operators: HashMap::from([("Ash".to_string(), 50), ("Thermite".to_string(), 20)]),
+        }
+    }
+
+    #[test]
+    fn snapshot_from_samples() {
+        let read = |filename: &str| std::fs::read_to_string(filename).unwrap();
+        let profiles: RankedV2Response =
+            serde_json::from_str(&read("../samples/full_profile.json")).unwrap();
+        let operators = serde_json::from_str(&read("../samples/operators.json")).unwrap();
+
+        let snapshot = SessionSnapshot::new(
+            Utc::now(),
+            Uuid::nil(),
+            PlatformFamily::Pc,
+            &profiles,
+            &operators,
+        );
+
+        let ranked = profiles
+            .get_board(PlatformFamily::Pc, GameMode::Ranked)
+            .unwrap()
+            .season_statistics();
+        assert!(snapshot.kills >= *ranked.kills());
+        assert!(snapshot.wins >= *ranked.match_outcomes().wins());
+        assert_eq!(snapshot.rank_points, Some(1300));
+        assert!(!snapshot.operators.is_empty());
+    }
+
+    #[test]
+    fn summary_of_session() {
+        let start = snapshot(20, 100, Some(2500));
+        let mut end = snapshot(23, 130, Some(2560));
+        end.taken = start.taken + Duration::minutes(95);
+        end.losses = 12;
+        end.deaths = 120;
+        end.operators.insert("Ash".to_string(), 65);
+        end.operators.insert("Zofia".to_string(), 15);
+
+        let summary = start.summary(&end);
+
+        assert_eq!(summary.duration, Duration::minutes(95));
+        assert_eq!(summary.matches(), 5);
+        assert_eq!(summary.win_rate(), Some(0.6));
+        assert_eq!(summary.kd(), Some(1.5));
+        assert_eq!(summary.rank_points, Some(60));
+        assert_eq!(
+            summary.operators,
+            vec![("Ash".to_string(), 15), ("Zofia".to_string(), 15)]
+        );
+    }
+
+    #[test]
+    fn summary_across_season_reset() {
+        let start = snapshot(20, 100, Some(2500));
+        let end = snapshot(2, 10, None);
+
+        let summary = start.summary(&end);
+
+        assert_eq!(summary.matches(), 0);
+        assert_eq!(summary.kills, 0);
+        assert_eq!(summary.kd(), None);
+        assert_eq!(summary.rank_points, None);
+        assert!(summary.operators.is_empty());
+    }
+
+    #[test]
+    fn load_from_disk_without_existing_file() {
+        assert!(PlaySessionLookupImpl::load("not existing file").is_ok());
+    }
+
+    #[test]
+    fn insert_load_and_remove() {
+        let user_id = UserId::from(1290213);
+
+        let file = NamedTempFile::new().unwrap();
+        let filename = file.path().to_str().unwrap().to_string();
+        std::fs::write(&filename, "{}").unwrap();
+
+        let mut lookup = PlaySessionLookupImpl::load(filename.as_str()).unwrap();
+        lookup
+            .insert(&user_id, snapshot(20, 100, Some(2500)))
+            .expect("should be able to persist");
+
+        // Act - Load from the existing file
+        let mut lookup = PlaySessionLookupImpl::load(filename.as_str()).unwrap();
+        assert_eq!(lookup.get(&user_id), Some(snapshot(20, 100, Some(2500))));
+
+        lookup.remove(&user_id).expect("should be able to persist");
+        let lookup = PlaySessionLookupImpl::load(filename.as_str()).unwrap();
+        assert_eq!(lookup.get(&user_id), None);
+    }
+}
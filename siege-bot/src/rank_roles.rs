@@ -0,0 +1,228 @@
+//! Gives linked members the role of their rank tier, using the roles of each
+//! guild in `[rank_roles]`.
+
+use std::{collections::HashMap, sync::Arc};
+
+use serenity::{
+    http::Http,
+    model::prelude::{Member, RoleId},
+    prelude::{RwLock, TypeMap},
+};
+use siege_api::{
+    data::rank::{Rank, RankTier},
+    models::{GameMode, PlatformFamily, RankedV2Response},
+};
+use uuid::Uuid;
+
+use crate::{
+    config::RankRolesConfig, guild_settings::guild_defaults,
+    siege_player_lookup::SiegePlayerLookup, SiegeApi,
+};
+
+/// Roles to give to and take from a member.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RoleChanges {
+    pub add: Vec<RoleId>,
+    pub remove: Vec<RoleId>,
+}
+
+impl RoleChanges {
+    pub fn is_empty(&self) -> bool {
+        self.add.is_empty() && self.remove.is_empty()
+    }
+}
+
+/// Get the changes needed for a member to only have the role of the tier, out
+/// of the rank roles of the guild. Roles not used for ranks are left alone.
+pub fn role_changes(
+    roles: &HashMap<RankTier, RoleId>,
+    member_roles: &[RoleId],
+    tier: RankTier,
+) -> RoleChanges {
+    let wanted = roles.get(&tier);
+
+    let mut remove = roles
+        .values()
+        .filter(|role| Some(*role) != wanted && member_roles.contains(role))
+        .copied()
+        .collect::<Vec<_>>();
+    remove.sort();
+
+    RoleChanges {
+        add: wanted
+            .filter(|role| !member_roles.contains(role))
+            .copied()
+            .into_iter()
+            .collect(),
+        remove,
+    }
+}
+
+/// The current rank of a player on the platform, which is unranked if the
+/// player has not played ranked this season.
+pub fn current_rank(profiles: &RankedV2Response, platform: PlatformFamily) -> Rank {
+    profiles
+        .get_board(platform, GameMode::Ranked)
+        .and_then(|board| Rank::from_repr(*board.profile().rank()))
+        .unwrap_or(Rank::Unranked)
+}
+
+/// Give the member the role of the tier of the rank, and take away the roles
+/// of the other tiers.
+pub async fn update_roles(
+    http: &Http,
+    member: &mut Member,
+    roles: &HashMap<RankTier, RoleId>,
+    rank: Rank,
+) -> serenity::Result<RoleChanges> {
+    let changes = role_changes(roles, &member.roles, rank.tier());
+    if !changes.add.is_empty() {
+        member.add_roles(http, &changes.add).await?;
+    }
+    if !changes.remove.is_empty() {
+        member.remove_roles(http, &changes.remove).await?;
+    }
+
+    Ok(changes)
+}
+
+/// Update the rank roles of every linked member of the configured guilds in
+/// the background.
+pub async fn watch(http: Arc<Http>, data: Arc<RwLock<TypeMap>>) {
+    let config = data
+        .read()
+        .await
+        .get::<RankRolesConfig>()
+        .expect("always registered")
+        .clone();
+    if config.guilds.is_empty() {
+        return;
+    }
+
+    let mut interval = tokio::time::interval(config.sync_interval());
+    loop {
+        interval.tick().await;
+        sync(&http, &data, &config).await;
+    }
+}
+
+async fn sync(http: &Http, data: &RwLock<TypeMap>, config: &RankRolesConfig) {
+    let (players, siege_client, platforms) = {
+        let data = data.read().await;
+        let players = data
+            .get::<SiegePlayerLookup>()
+            .expect("always registered")
+            .read()
+            .await
+            .all();
+        let siege_client = data
+            .get::<SiegeApi>()
+            .expect("client always registered")
+            .clone();
+        let mut platforms = HashMap::new();
+        for guild_id in config.guilds.keys() {
+            let defaults = guild_defaults(&data, Some(*guild_id)).await;
+            platforms.insert(*guild_id, defaults.platform);
+        }
+
+        (players, siege_client, platforms)
+    };
+
+    // Members of several guilds only have their profiles fetched once.
+    let mut profiles: HashMap<Uuid, RankedV2Response> = HashMap::new();
+
+    for (guild_id, roles) in &config.guilds {
+        tracing::info!("Syncing rank roles in {guild_id}");
+
+        for (user_id, player_id) in &players {
+            // Most linked players are usually not in every guild.
+            let mut member = match guild_id.member(http, user_id).await {
+                Ok(member) => member,
+                Err(err) => {
+                    tracing::debug!("Skipping {user_id} in {guild_id}: {err:?}");
+                    continue;
+                }
+            };
+
+            if !profiles.contains_key(player_id) {
+                match siege_client.get_full_profiles(*player_id).await {
+                    Ok(response) => {
+                        profiles.insert(*player_id, response);
+                    }
+                    Err(err) => {
+                        tracing::warn!("Failed to fetch the rank of {player_id}: {err:?}");
+                        continue;
+                    }
+                }
+            }
+
+            let rank = current_rank(&profiles[player_id], platforms[guild_id]);
+            match update_roles(http, &mut member, roles, rank).await {
+                Ok(changes) if changes.is_empty() => {}
+                Ok(changes) => tracing::info!(
+                    "Updated the rank roles of {user_id} in {guild_id} to {rank}: {changes:?}"
+                ),
+                Err(err) => tracing::error!(
+                    "Failed to update the rank roles of {user_id} in {guild_id}: {err:?}"
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn roles() -> HashMap<RankTier, RoleId> {
+        HashMap::from([
+            (RankTier::Gold, RoleId(1)),
+            (RankTier::Platinum, RoleId(2)),
+            (RankTier::Diamond, RoleId(3)),
+        ])
+    }
+
+    #[test]
+    fn role_changes_adds_tier_and_removes_others() {
+        let changes = role_changes(
+            &roles(),
+            &[RoleId(1), RoleId(3), RoleId(99)],
+            RankTier::Platinum,
+        );
+
+        assert_eq!(
+            changes,
+            RoleChanges {
+                add: vec![RoleId(2)],
+                remove: vec![RoleId(1), RoleId(3)],
+            }
+        );
+    }
+
+    #[test]
+    fn role_changes_without_changes() {
+        assert!(role_changes(&roles(), &[RoleId(2), RoleId(99)], RankTier::Platinum).is_empty());
+        assert!(role_changes(&roles(), &[RoleId(99)], RankTier::Copper).is_empty());
+    }
+
+    #[test]
+    fn role_changes_for_tier_without_role() {
+        let changes = role_changes(&roles(), &[RoleId(1)], RankTier::Unranked);
+
+        assert!(changes.add.is_empty());
+        assert_eq!(changes.remove, vec![RoleId(1)]);
+    }
+
+    #[test]
+    fn current_rank_from_profile() {
+        let content = std::fs::read_to_string("../samples/full_profile.json").unwrap();
+        let profiles: RankedV2Response = serde_json::from_str(content.as_str()).unwrap();
+
+        assert_eq!(current_rank(&profiles, PlatformFamily::Pc), Rank::CopperII);
+        // The sample has no console profiles.
+        assert_eq!(
+            current_rank(&profiles, PlatformFamily::Console),
+            Rank::Unranked
+        );
+    }
+}
@@ -0,0 +1,31 @@
+//! Charts of statistics, rendered as PNG images to attach to embeds. The
+//! charts are only rendered with the `charts` feature, which is enabled by
+//! default. Without it, the embeds are sent without a chart.
+
+#[cfg(feature = "charts")]
+mod charts;
+
+#[cfg(feature = "charts")]
+pub use charts::{map_win_rate_chart, operator_kd_chart, profile_chart};
+
+#[cfg(not(feature = "charts"))]
+mod disabled {
+    use siege_api::models::{MapStatistics, OperatorStatistics, Statistics};
+
+    use crate::commands::discord_app_command::Attachment;
+
+    pub fn operator_kd_chart(_: &[&OperatorStatistics]) -> Option<Attachment> {
+        None
+    }
+
+    pub fn map_win_rate_chart(_: &[&MapStatistics]) -> Option<Attachment> {
+        None
+    }
+
+    pub fn profile_chart(_: &Statistics) -> Option<Attachment> {
+        None
+    }
+}
+
+#[cfg(not(feature = "charts"))]
+pub use disabled::{map_win_rate_chart, operator_kd_chart, profile_chart};
@@ -0,0 +1,402 @@
+//! Charts drawn with `plotters` into an RGB buffer, which is encoded as PNG.
+
+use std::{f64::consts::PI, sync::Once};
+
+use plotters::{
+    coord::Shift,
+    prelude::*,
+    style::{
+        register_font,
+        text_anchor::{HPos, Pos, VPos},
+    },
+};
+use siege_api::models::{MapStatistics, OperatorStatistics, Statistics};
+
+use crate::{
+    commands::discord_app_command::Attachment,
+    formatting::{decimal, percentage},
+};
+
+/// Same as the background of embeds in the dark theme of Discord.
+const BACKGROUND: RGBColor = RGBColor(0x2F, 0x31, 0x36);
+const TEXT: RGBColor = RGBColor(0xDC, 0xDD, 0xDE);
+const GRID: RGBColor = RGBColor(0x4F, 0x54, 0x5C);
+/// Same as `Color::TEAL` used for the embeds.
+const PRIMARY: RGBColor = RGBColor(0x1A, 0xBC, 0x9C);
+const SECONDARY: RGBColor = RGBColor(0xE6, 0x7E, 0x22);
+
+/// Name the bundled font is registered with. A monospaced font makes it easy
+/// to tell whether a label fits.
+const FONT: &str = "DejaVu Sans Mono";
+const FONT_SIZE: f64 = 16.0;
+
+const WIDTH: u32 = 640;
+const MARGIN: u32 = 16;
+const CAPTION_HEIGHT: u32 = 32;
+const AXIS_HEIGHT: u32 = 32;
+const LABEL_WIDTH: u32 = 240;
+/// Longest name in a label, so the name and value fit in `LABEL_WIDTH`.
+const MAX_NAME_LENGTH: usize = 14;
+const ROW_HEIGHT: u32 = 24;
+
+const RADAR_SIZE: u32 = 560;
+const RADAR_RADIUS: f64 = 150.0;
+
+/// Lowest maximum of the scale for K/D, so a K/D of 1.0 is not at the edge of
+/// the chart.
+const MIN_RATIO_MAX: f64 = 1.5;
+
+type DrawResult<'a> =
+    Result<(), DrawingAreaErrorKind<<BitMapBackend<'a> as DrawingBackend>::ErrorType>>;
+
+/// Horizontal bars with the K/D of each operator, in the given order.
+pub fn operator_kd_chart(operators: &[&OperatorStatistics]) -> Option<Attachment> {
+    let rows = operators
+        .iter()
+        .map(|x| {
+            let kd = *x.statistics().kill_death_ratio();
+            (label(&x.name().to_string(), &decimal(Some(kd))), Some(kd))
+        })
+        .collect::<Vec<_>>();
+    let max = round_up(
+        rows.iter()
+            .filter_map(|x| x.1)
+            .fold(MIN_RATIO_MAX, f64::max),
+        0.5,
+    );
+
+    Some(bar_chart(
+        "operators.png",
+        "K/D per operator",
+        &rows,
+        (max, 1.0),
+        |x| format!("{x:.1}"),
+    ))
+}
+
+/// Horizontal bars with the round win rate of each map, in the given order.
+pub fn map_win_rate_chart(maps: &[&MapStatistics]) -> Option<Attachment> {
+    let rows = maps
+        .iter()
+        .map(|x| {
+            let win_rate = x.statistics().rounds_win_rate();
+            (
+                label(&x.name().to_string(), &percentage(win_rate)),
+                win_rate,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    Some(bar_chart(
+        "maps.png",
+        "Round win rate per map",
+        &rows,
+        (1.0, 0.5),
+        |x| format!("{:.0} %", 100.0 * x),
+    ))
+}
+
+/// Radar chart comparing the strengths of a player. Every axis is scaled to
+/// the range of 0 to 1, where the K/D and rating are halved so an average
+/// player ends up in the middle.
+pub fn profile_chart(statistics: &Statistics) -> Option<Attachment> {
+    let metrics = statistics.derived_metrics();
+    let kd = Some(*statistics.kill_death_ratio());
+
+    let axes = [
+        (
+            "Win",
+            statistics.matches_win_rate(),
+            percentage(statistics.matches_win_rate()),
+        ),
+        ("K/D", kd.map(|x| x / 2.0), decimal(kd)),
+        (
+            "Rating",
+            metrics.rating().map(|x| x / 2.0),
+            decimal(*metrics.rating()),
+        ),
+        (
+            "HS",
+            *metrics.headshot_percentage(),
+            percentage(*metrics.headshot_percentage()),
+        ),
+        (
+            "Entry",
+            *metrics.entry_success(),
+            percentage(*metrics.entry_success()),
+        ),
+        (
+            "Survival",
+            Some(*statistics.rounds_survived()),
+            percentage(Some(*statistics.rounds_survived())),
+        ),
+    ];
+
+    Some(render("profile.png", (RADAR_SIZE, RADAR_SIZE), |root| {
+        let root = root.margin(MARGIN, MARGIN, MARGIN, MARGIN);
+        let (title, root) = root.split_vertically(CAPTION_HEIGHT);
+        title.draw(&Text::new("Profile", (0, 0), text_style()))?;
+
+        let (width, height) = root.dim_in_pixel();
+        let center = (width as f64 / 2.0, height as f64 / 2.0);
+        // Start at the top and go clockwise.
+        let point = |axis: usize, distance: f64| {
+            let angle = 2.0 * PI * axis as f64 / axes.len() as f64 - PI / 2.0;
+            (
+                (center.0 + angle.cos() * distance).round() as i32,
+                (center.1 + angle.sin() * distance).round() as i32,
+            )
+        };
+
+        for step in [0.25, 0.5, 0.75, 1.0] {
+            let ring = (0..=axes.len())
+                .map(|axis| point(axis % axes.len(), step * RADAR_RADIUS))
+                .collect::<Vec<_>>();
+            root.draw(&PathElement::new(ring, GRID))?;
+        }
+
+        let centered = text_style().pos(Pos::new(HPos::Center, VPos::Center));
+        for (axis, (name, _, value)) in axes.iter().enumerate() {
+            root.draw(&PathElement::new(
+                [point(axis, 0.0), point(axis, RADAR_RADIUS)],
+                GRID,
+            ))?;
+            root.draw(&Text::new(
+                format!("{name} {value}"),
+                point(axis, RADAR_RADIUS + 32.0),
+                centered.clone(),
+            ))?;
+        }
+
+        let shape = axes
+            .iter()
+            .enumerate()
+            .map(|(axis, (_, value, _))| {
+                point(axis, value.unwrap_or(0.0).clamp(0.0, 1.0) * RADAR_RADIUS)
+            })
+            .collect::<Vec<_>>();
+        root.draw(&Polygon::new(shape.clone(), PRIMARY.mix(0.4).filled()))?;
+        root.draw(&PathElement::new(
+            shape
+                .iter()
+                .chain(shape.first())
+                .copied()
+                .collect::<Vec<_>>(),
+            PRIMARY.stroke_width(2),
+        ))
+    }))
+}
+
+/// Draw a chart of horizontal bars, with the label of every row on the left.
+/// The scale goes from zero up to `max` and `reference` is marked with a line.
+fn bar_chart(
+    filename: &str,
+    title: &str,
+    rows: &[(String, Option<f64>)],
+    (max, reference): (f64, f64),
+    format_scale: fn(&f64) -> String,
+) -> Attachment {
+    let height = 2 * MARGIN + CAPTION_HEIGHT + rows.len() as u32 * ROW_HEIGHT + AXIS_HEIGHT;
+
+    render(filename, (WIDTH, height), |root| {
+        // The segments are counted from the bottom, while the first row
+        // should be at the top.
+        let last = rows.len().saturating_sub(1);
+        let mut chart = ChartBuilder::on(root)
+            .caption(title, text_style())
+            .margin(MARGIN)
+            // Room for the label at the end of the scale.
+            .margin_right(3 * MARGIN)
+            .x_label_area_size(AXIS_HEIGHT)
+            .y_label_area_size(LABEL_WIDTH)
+            .build_cartesian_2d(0.0..max, (0..last).into_segmented())?;
+
+        chart
+            .configure_mesh()
+            .disable_y_mesh()
+            .x_labels(5)
+            .x_label_formatter(&format_scale)
+            .y_labels(rows.len())
+            .y_label_formatter(&|y| match y {
+                SegmentValue::CenterOf(i) if *i <= last && !rows.is_empty() => {
+                    rows[last - i].0.clone()
+                }
+                _ => String::new(),
+            })
+            .label_style(text_style())
+            .axis_style(GRID)
+            .bold_line_style(GRID)
+            .light_line_style(TRANSPARENT)
+            .draw()?;
+
+        chart.draw_series(rows.iter().enumerate().filter_map(|(i, (_, value))| {
+            value.map(|value| {
+                let y = last - i;
+                let mut bar = Rectangle::new(
+                    [
+                        (0.0, SegmentValue::Exact(y)),
+                        (value.min(max), SegmentValue::Exact(y + 1)),
+                    ],
+                    PRIMARY.filled(),
+                );
+                bar.set_margin(4, 4, 0, 0);
+                bar
+            })
+        }))?;
+
+        chart.draw_series([PathElement::new(
+            [
+                (reference, SegmentValue::Exact(0)),
+                (reference, SegmentValue::Last),
+            ],
+            SECONDARY.stroke_width(2),
+        )])?;
+
+        Ok(())
+    })
+}
+
+/// Draw onto a canvas of the given size and encode it as a PNG image.
+fn render<F>(filename: &str, (width, height): (u32, u32), draw: F) -> Attachment
+where
+    F: for<'a> FnOnce(&DrawingArea<BitMapBackend<'a>, Shift>) -> DrawResult<'a>,
+{
+    register_bundled_font();
+
+    let mut pixels = vec![0; (width * height * 3) as usize];
+    {
+        let root = BitMapBackend::with_buffer(&mut pixels, (width, height)).into_drawing_area();
+        root.fill(&BACKGROUND)
+            .and_then(|_| draw(&root))
+            .and_then(|_| root.present())
+            .expect("drawing into a buffer cannot fail");
+    }
+
+    Attachment {
+        filename: filename.to_string(),
+        data: encode(width, height, &pixels),
+    }
+}
+
+/// Encode the pixels, given as RGB triples row by row, as a PNG image.
+fn encode(width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+    let mut data = Vec::new();
+    let mut encoder = png::Encoder::new(&mut data, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(pixels))
+        .expect("writing to a vector cannot fail");
+
+    data
+}
+
+/// Register the font shipped with the bot, so the charts look the same
+/// without any fonts installed.
+fn register_bundled_font() {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let font = include_bytes!("../../assets/DejaVuSansMono.ttf");
+        assert!(
+            register_font(FONT, FontStyle::Normal, font).is_ok(),
+            "the bundled font should be valid"
+        );
+    });
+}
+
+fn text_style() -> TextStyle<'static> {
+    (FONT, FONT_SIZE).into_font().color(&TEXT)
+}
+
+/// Label of a row with the name, shortened with `.` if needed, and value.
+fn label(name: &str, value: &str) -> String {
+    let name = match name.chars().count() > MAX_NAME_LENGTH {
+        true => format!(
+            "{}.",
+            name.chars().take(MAX_NAME_LENGTH - 1).collect::<String>()
+        ),
+        false => name.to_string(),
+    };
+
+    format!("{name:MAX_NAME_LENGTH$} {value}")
+}
+
+/// Round the value up to the next multiple of `step`.
+fn round_up(value: f64, step: f64) -> f64 {
+    (value / step).ceil() * step
+}
+
+#[cfg(test)]
+mod test {
+    use siege_api::models::{GameModeOrAll, SideOrAll, StatisticResponse};
+
+    use super::*;
+
+    const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+
+    fn load(file: &str) -> StatisticResponse {
+        let content = std::fs::read_to_string(format!("../samples/{file}")).unwrap();
+        serde_json::from_str(&content).unwrap()
+    }
+
+    /// Read the width and height from the header of the image.
+    fn dimensions(png: &[u8]) -> (u32, u32) {
+        assert_eq!(png[..8], PNG_SIGNATURE);
+        (
+            u32::from_be_bytes(png[16..20].try_into().unwrap()),
+            u32::from_be_bytes(png[20..24].try_into().unwrap()),
+        )
+    }
+
+    #[test]
+    fn operator_chart_has_row_per_operator() {
+        let stats = load("operators.json");
+        let operators = stats.get_operators(GameModeOrAll::All, SideOrAll::Attacker);
+
+        let chart = operator_kd_chart(&operators).unwrap();
+        assert_eq!(chart.filename, "operators.png");
+
+        let expected = 2 * MARGIN + CAPTION_HEIGHT + operators.len() as u32 * ROW_HEIGHT;
+        assert_eq!(dimensions(&chart.data), (WIDTH, expected + AXIS_HEIGHT));
+    }
+
+    #[test]
+    fn map_chart() {
+        let stats = load("maps.json");
+        let maps = stats.get_maps(GameModeOrAll::All, SideOrAll::All);
+
+        let chart = map_win_rate_chart(&maps).unwrap();
+        assert_eq!(chart.filename, "maps.png");
+        assert_eq!(dimensions(&chart.data).0, WIDTH);
+    }
+
+    #[test]
+    fn empty_chart() {
+        let chart = operator_kd_chart(&[]).unwrap();
+        assert_eq!(
+            dimensions(&chart.data),
+            (WIDTH, 2 * MARGIN + CAPTION_HEIGHT + AXIS_HEIGHT)
+        );
+    }
+
+    #[test]
+    fn profile() {
+        let stats = load("operators.json");
+        let operators = stats.get_operators(GameModeOrAll::All, SideOrAll::All);
+
+        let chart = profile_chart(operators[0].statistics()).unwrap();
+        assert_eq!(chart.filename, "profile.png");
+        assert_eq!(dimensions(&chart.data), (RADAR_SIZE, RADAR_SIZE));
+    }
+
+    #[test]
+    fn shorten_labels() {
+        assert_eq!(label("Ash", "1.20"), "Ash            1.20");
+        assert_eq!(
+            label("Kafe Dostoyevsky", "50.00 %"),
+            "Kafe Dostoyev. 50.00 %"
+        );
+    }
+}
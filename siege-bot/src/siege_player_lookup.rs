@@ -1,6 +1,7 @@
 use std::{
     collections::HashMap,
-    fs::{read_to_string, write},
+    fs::write,
+    path::{Path, PathBuf},
     sync::Arc,
 };
 
@@ -10,6 +11,8 @@ use serenity::{
 };
 use uuid::Uuid;
 
+use crate::config::StorageConfig;
+
 pub struct SiegePlayerLookup;
 impl TypeMapKey for SiegePlayerLookup {
     type Value = Arc<RwLock<dyn PlayerLookup>>;
@@ -18,12 +21,13 @@ impl TypeMapKey for SiegePlayerLookup {
 #[cfg_attr(test, mockall::automock)]
 pub trait PlayerLookup: Send + Sync {
     fn get<'a>(&'a self, id: &UserId) -> Option<&'a Uuid>;
+    fn all(&self) -> Vec<(UserId, Uuid)>;
     fn insert(&mut self, id: &UserId, siege_id: Uuid) -> Result<(), std::io::Error>;
 }
 
 #[derive(Debug)]
 pub struct PlayerLookupImpl {
-    filename: String,
+    path: PathBuf,
     users: HashMap<UserId, Uuid>,
 }
 
@@ -33,6 +37,11 @@ impl PlayerLookup for PlayerLookupImpl {
         self.users.get(id)
     }
 
+    /// Get all the linked Discord users with their Ubisoft ID.
+    fn all(&self) -> Vec<(UserId, Uuid)> {
+        self.users.iter().map(|(u, s)| (*u, *s)).collect()
+    }
+
     /// Insert a Discord user's Ubisoft ID for later lookup.
     fn insert(&mut self, id: &UserId, siege_id: Uuid) -> Result<(), std::io::Error> {
         self.users.insert(*id, siege_id);
@@ -41,10 +50,9 @@ impl PlayerLookup for PlayerLookupImpl {
 }
 
 impl PlayerLookupImpl {
-    pub fn load(filename: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let users = match read_to_string(filename)
-            .or_else(|_| read_to_string(format!("/config/{filename}")))
-        {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        let users = match StorageConfig::read(path) {
             Ok(content) => serde_json::from_str(content.as_str())?,
             Err(err) => {
                 tracing::warn!("Failed to read players. Creating default. Error: {err:?}");
@@ -53,7 +61,7 @@ impl PlayerLookupImpl {
         };
 
         Ok(Self {
-            filename: filename.to_owned(),
+            path: path.to_path_buf(),
             users,
         })
     }
@@ -61,7 +69,7 @@ impl PlayerLookupImpl {
     fn persist(&self) -> Result<(), std::io::Error> {
         let content =
             serde_json::to_string_pretty(&self.users).expect("should always be serializeable");
-        write(self.filename.as_str(), content)
+        write(&self.path, content)
     }
 }
 
@@ -101,6 +109,7 @@ mod test {
 
         // Assert
         assert_eq!(*lookup.get(&discord_id).unwrap(), siege_id);
+        assert_eq!(lookup.all(), vec![(discord_id, siege_id)]);
 
         drop(file);
     }
@@ -110,7 +119,9 @@ mod test {
         let siege_id =
             Uuid::parse_str("68830784-0ff1-43c7-bbac-90c1e537d1cc").expect("this is a valid guid");
         let discord_id = UserId::from(1290213);
-        let mut lookup = PlayerLookupImpl::load("temp").unwrap();
+        let file = NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "{}").unwrap();
+        let mut lookup = PlayerLookupImpl::load(file.path().to_str().unwrap()).unwrap();
 
         // Act - add
         lookup
@@ -126,13 +137,13 @@ mod test {
     #[test]
     fn debug() {
         let lookup = PlayerLookupImpl {
-            filename: "some name".to_string(),
+            path: PathBuf::from("some name"),
             users: HashMap::default(),
         };
 
         assert_eq!(
             format!("{lookup:?}"),
-            "PlayerLookupImpl { filename: \"some name\", users: {} }"
+            "PlayerLookupImpl { path: \"some name\", users: {} }"
         );
     }
 }
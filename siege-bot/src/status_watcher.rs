@@ -0,0 +1,259 @@
+use std::{collections::BTreeSet, sync::Arc, time::Duration};
+
+use serenity::{
+    builder::CreateEmbed,
+    http::Http,
+    model::Timestamp,
+    prelude::{RwLock, TypeMap},
+    utils::Color,
+};
+use siege_api::models::meta::{GameStatus, Platform, Status, SIEGE_TITLE};
+
+use crate::{notification_channels::NotificationChannels, SiegeApi};
+
+/// Describes whether maintenance started or ended between two polls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+pub enum MaintenanceChange {
+    #[strum(serialize = "Maintenance started")]
+    Started,
+    #[strum(serialize = "Maintenance ended")]
+    Ended,
+}
+
+/// A change in the status of a single game instance between two polls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusChange {
+    pub name: String,
+    pub platform: Platform,
+    pub previous: Status,
+    pub current: Status,
+    pub maintenance: Option<MaintenanceChange>,
+    pub impacted_features: Vec<String>,
+}
+
+/// Compare two snapshots of the game status and return every instance that
+/// has changed its status, maintenance flag or impacted features.
+///
+/// Instances are matched by their name, as Ubisoft reports several instances
+/// for the same platform (e.g. `PC` and `China - PC`). Instances which are
+/// only present in one of the snapshots are ignored.
+pub fn diff(previous: &[GameStatus], current: &[GameStatus]) -> Vec<StatusChange> {
+    current
+        .iter()
+        .filter_map(|now| {
+            let before = previous.iter().find(|x| x.name() == now.name())?;
+
+            let maintenance = match (
+                before.maintenance().unwrap_or(false),
+                now.maintenance().unwrap_or(false),
+            ) {
+                (false, true) => Some(MaintenanceChange::Started),
+                (true, false) => Some(MaintenanceChange::Ended),
+                _ => None,
+            };
+            let features_before = before.impacted_features().iter().collect::<BTreeSet<_>>();
+            let features_now = now.impacted_features().iter().collect::<BTreeSet<_>>();
+
+            if before.status() == now.status()
+                && maintenance.is_none()
+                && features_before == features_now
+            {
+                return None;
+            }
+
+            Some(StatusChange {
+                name: now.name().clone(),
+                platform: *now.platform(),
+                previous: before.status().clone(),
+                current: now.status().clone(),
+                maintenance,
+                impacted_features: features_now.into_iter().cloned().collect(),
+            })
+        })
+        .collect()
+}
+
+/// Create an embed describing the given status changes.
+pub fn create_embed(changes: &[StatusChange]) -> CreateEmbed {
+    let mut embed = CreateEmbed::default();
+    embed
+        .title("Rainbow Six Siege status changed")
+        .timestamp(Timestamp::now())
+        .color(
+            match changes
+                .iter()
+                .map(|x| &x.current)
+                .find(|x| **x != Status::Online)
+            {
+                None => Color::DARK_GREEN,
+                Some(Status::Degraded) => Color::ORANGE,
+                Some(_) => Color::DARK_RED,
+            },
+        );
+
+    changes.iter().for_each(|change| {
+        let mut lines = vec![if change.previous == change.current {
+            format!("Status: **{}**", change.current)
+        } else {
+            format!("**{}** → **{}**", change.previous, change.current)
+        }];
+        if let Some(maintenance) = change.maintenance {
+            lines.push(maintenance.to_string());
+        }
+        if !change.impacted_features.is_empty() {
+            lines.push(format!(
+                "Impacted features: {}",
+                change.impacted_features.join(", ")
+            ));
+        }
+
+        embed.field(&change.name, lines.join("\n"), false);
+    });
+
+    embed
+}
+
+/// Poll the game status in the background and post a message to every
+/// registered notification channel whenever the status changes.
+///
+/// The first poll is only used as a baseline, so nothing is posted on startup.
+pub async fn watch(http: Arc<Http>, data: Arc<RwLock<TypeMap>>, poll_interval: Duration) {
+    let mut interval = tokio::time::interval(poll_interval);
+    let mut previous: Option<Vec<GameStatus>> = None;
+
+    loop {
+        interval.tick().await;
+
+        let current = {
+            let data = data.read().await;
+            let siege_client = data.get::<SiegeApi>().expect("client always registered");
+            match siege_client
+                .siege_status(Some(SIEGE_TITLE.to_string()))
+                .await
+            {
+                Ok(status) => status,
+                Err(err) => {
+                    tracing::warn!("Failed to poll game status: {err:?}");
+                    continue;
+                }
+            }
+        };
+
+        if let Some(previous) = previous.as_ref() {
+            let changes = diff(previous, &current);
+            if !changes.is_empty() {
+                tracing::info!("Game status changed: {changes:?}");
+                notify(&http, &data, &changes).await;
+            }
+        }
+
+        previous = Some(current);
+    }
+}
+
+async fn notify(http: &Http, data: &RwLock<TypeMap>, changes: &[StatusChange]) {
+    let channels = {
+        let data = data.read().await;
+        let lookup = data
+            .get::<NotificationChannels>()
+            .expect("always registered");
+        let channels = lookup.read().await.all();
+        channels
+    };
+
+    for (guild_id, channel_id) in channels {
+        if let Err(err) = channel_id
+            .send_message(http, |message| message.set_embed(create_embed(changes)))
+            .await
+        {
+            tracing::error!("Failed to send status change to {guild_id}/{channel_id}: {err:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn load_status(filename: &str) -> Vec<GameStatus> {
+        let content = std::fs::read_to_string(filename).unwrap();
+        let statuses: Vec<GameStatus> = serde_json::from_str(content.as_str()).unwrap();
+        statuses
+            .into_iter()
+            .filter(|x| x.name().starts_with(SIEGE_TITLE))
+            .collect()
+    }
+
+    #[test]
+    fn diff_without_changes() {
+        let status = load_status("../samples/game-status.json");
+
+        assert!(diff(&status, &status).is_empty());
+    }
+
+    #[test]
+    fn diff_online_to_degraded() {
+        let online = load_status("../samples/game-status.json");
+        let degraded = load_status("../samples/game-status-with-error.json");
+
+        let changes = diff(&online, &degraded);
+
+        assert_eq!(changes.len(), 3);
+        assert_eq!(
+            changes.iter().map(|x| x.platform).collect::<Vec<_>>(),
+            vec![Platform::PC, Platform::PS4, Platform::XboxOne]
+        );
+        changes.iter().for_each(|change| {
+            assert_eq!(change.previous, Status::Online);
+            assert_eq!(change.current, Status::Degraded);
+            assert_eq!(change.maintenance, None);
+            assert_eq!(
+                change.impacted_features,
+                vec!["Authentication", "Leaderboard", "Matchmaking", "Purchase"]
+            );
+        });
+    }
+
+    #[test]
+    fn diff_degraded_to_online() {
+        let online = load_status("../samples/game-status.json");
+        let degraded = load_status("../samples/game-status-with-error.json");
+
+        let changes = diff(&degraded, &online);
+
+        assert_eq!(changes.len(), 3);
+        changes.iter().for_each(|change| {
+            assert_eq!(change.current, Status::Online);
+            assert!(change.impacted_features.is_empty());
+        });
+    }
+
+    #[test]
+    fn diff_ignores_unknown_instances() {
+        let online = load_status("../samples/game-status.json");
+
+        assert!(diff(&[], &online).is_empty());
+        assert!(diff(&online, &[]).is_empty());
+    }
+
+    #[test]
+    fn embed_contains_a_field_per_change() {
+        let online = load_status("../samples/game-status.json");
+        let degraded = load_status("../samples/game-status-with-error.json");
+
+        let embed = create_embed(&diff(&online, &degraded));
+
+        let fields = embed.0.get("fields").unwrap().as_array().unwrap();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(
+            fields[0].get("name").unwrap(),
+            "Rainbow Six Siege - PC - LIVE"
+        );
+        assert!(fields[0]
+            .get("value")
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .starts_with("**Online** → **Degraded**"));
+    }
+}
@@ -0,0 +1,181 @@
+//! Analysis of the operators the players of a team main on each side, and
+//! the key roles the team is missing.
+
+use siege_api::{
+    game_models::{Role, Side},
+    models::{GameModeOrAll, SideOrAll, StatisticResponse},
+    operator::{get_operator_details, Operator},
+};
+
+/// Roles a team should have covered by its mains on each side, with the name
+/// used when the role is missing.
+fn key_roles(side: Side) -> &'static [(Role, &'static str)] {
+    match side {
+        Side::Attacker => &[
+            (Role::HardBreach, "hard breacher"),
+            (Role::SoftBreach, "soft breacher"),
+            (Role::IntelGatherer, "intel gatherer"),
+            (Role::Disable, "utility clearer"),
+            (Role::FrontLine, "entry fragger"),
+        ],
+        Side::Defender => &[
+            (Role::Anchor, "anchor"),
+            (Role::Roam, "roamer"),
+            (Role::AntiHardBreach, "anti hard breacher"),
+            (Role::IntelGatherer, "intel gatherer"),
+            (Role::Trap, "trapper"),
+        ],
+        Side::Unknown => &[],
+    }
+}
+
+/// An operator a player has played a lot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperatorMain {
+    pub operator: Operator,
+    pub rounds_played: u64,
+    pub kill_death_ratio: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerMains {
+    pub name: String,
+    pub operators: Vec<OperatorMain>,
+}
+
+/// The mains of every player in a team on one side, and the roles they cover.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamSide {
+    pub side: Side,
+    pub players: Vec<PlayerMains>,
+    /// Number of mains with each role, from the most to the least covered.
+    pub coverage: Vec<(Role, usize)>,
+    /// Names of the key roles none of the mains have.
+    pub gaps: Vec<&'static str>,
+}
+
+/// Get the operators a player has played the most rounds with on a side.
+pub fn top_operators(
+    response: &StatisticResponse,
+    game_mode: GameModeOrAll,
+    side: Side,
+    count: usize,
+) -> Vec<OperatorMain> {
+    let Ok(side) = SideOrAll::try_from(side) else {
+        return Vec::new();
+    };
+    let mut operators = response
+        .get_operators(game_mode, side)
+        .iter()
+        .filter(|x| *x.statistics().rounds_played() > 0)
+        .map(|x| OperatorMain {
+            operator: *x.name(),
+            rounds_played: *x.statistics().rounds_played(),
+            kill_death_ratio: *x.statistics().kill_death_ratio(),
+        })
+        .collect::<Vec<_>>();
+    operators.sort_by(|a, b| {
+        b.rounds_played.cmp(&a.rounds_played).then_with(|| {
+            b.kill_death_ratio
+                .partial_cmp(&a.kill_death_ratio)
+                .expect("should always be valid")
+        })
+    });
+    operators.truncate(count);
+
+    operators
+}
+
+/// Find the roles covered by the mains of the players, and the key roles
+/// which are not covered.
+pub fn analyze_side(side: Side, players: Vec<PlayerMains>) -> TeamSide {
+    let mut coverage: Vec<(Role, usize)> = Vec::new();
+    players
+        .iter()
+        .flat_map(|player| player.operators.iter())
+        .flat_map(|main| get_operator_details(main.operator).roles())
+        .for_each(|role| match coverage.iter_mut().find(|(x, _)| x == role) {
+            Some((_, count)) => *count += 1,
+            None => coverage.push((*role, 1)),
+        });
+    coverage.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then_with(|| a.0.to_string().cmp(&b.0.to_string()))
+    });
+
+    let gaps = key_roles(side)
+        .iter()
+        .filter(|(role, _)| !coverage.iter().any(|(x, _)| x == role))
+        .map(|(_, name)| *name)
+        .collect();
+
+    TeamSide {
+        side,
+        players,
+        coverage,
+        gaps,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn load_operators() -> StatisticResponse {
+        let content = std::fs::read_to_string("../samples/operators.json").unwrap();
+        serde_json::from_str(content.as_str()).unwrap()
+    }
+
+    fn mains(name: &str, operators: &[Operator]) -> PlayerMains {
+        PlayerMains {
+            name: name.to_string(),
+            operators: operators
+                .iter()
+                .map(|operator| OperatorMain {
+                    operator: *operator,
+                    rounds_played: 10,
+                    kill_death_ratio: 1.0,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn top_operators_by_rounds_played() {
+        let stats = load_operators();
+        let operators = top_operators(&stats, GameModeOrAll::All, Side::Defender, 3);
+
+        assert_eq!(operators.len(), 3);
+        assert!(operators
+            .windows(2)
+            .all(|x| x[0].rounds_played >= x[1].rounds_played));
+        assert!(operators
+            .iter()
+            .all(|x| get_operator_details(x.operator).side() == &Side::Defender));
+    }
+
+    #[test]
+    fn analyze_side_finds_gaps() {
+        let team = analyze_side(
+            Side::Attacker,
+            vec![
+                mains("First", &[Operator::Thermite, Operator::Ash]),
+                mains("Second", &[Operator::Sledge]),
+            ],
+        );
+
+        assert!(team.coverage.contains(&(Role::HardBreach, 1)));
+        assert!(!team.gaps.contains(&"hard breacher"));
+        assert!(team.gaps.contains(&"intel gatherer"));
+        assert!(team.coverage.windows(2).all(|x| x[0].1 >= x[1].1));
+    }
+
+    #[test]
+    fn analyze_side_without_mains() {
+        let team = analyze_side(Side::Defender, vec![mains("First", &[])]);
+
+        assert!(team.coverage.is_empty());
+        assert_eq!(team.gaps.len(), key_roles(Side::Defender).len());
+        assert!(team.gaps.contains(&"anchor"));
+    }
+}